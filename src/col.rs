@@ -2,6 +2,11 @@ use crate::{ColIter, Grid, GridMut};
 use std::ops::Deref;
 
 /// A single column of a grid.
+///
+/// Unlike rows, the values of a column are not next to each other in memory, so columns
+/// cannot be borrowed as slices. In a [`GridBuf`](crate::GridBuf), each value is `width`
+/// elements after the previous one; use [`col_stride`](crate::GridBuf::col_stride) to get
+/// the exact layout for bulk access.
 #[repr(C)]
 #[derive(Clone)]
 pub struct Col<GridRef> {
@@ -91,6 +96,42 @@ impl<'a, G: Grid> Col<&'a G> {
     pub fn iter(&self) -> ColIter<&'a G> {
         ColIter::new(self.grid, self.x, self.len())
     }
+
+    /// Collect clones of all values in the column into a [`Vec`], from top to bottom.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let names = [
+    ///     [String::from("a"), String::from("b")],
+    ///     [String::from("c"), String::from("d")],
+    /// ];
+    /// assert_eq!(names.col(1).cloned_to_vec(), vec!["b", "d"]);
+    /// ```
+    #[inline]
+    pub fn cloned_to_vec(&self) -> Vec<G::Item>
+    where
+        G::Item: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Collect copies of all values in the column into a [`Vec`], from top to bottom.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    /// assert_eq!(nums.col(2).copied_to_vec(), vec![3, 6]);
+    /// ```
+    #[inline]
+    pub fn copied_to_vec(&self) -> Vec<G::Item>
+    where
+        G::Item: Copy,
+    {
+        self.iter().copied().collect()
+    }
 }
 
 impl<'a, G: GridMut> Col<&'a mut G> {
@@ -138,6 +179,22 @@ impl<'a, G: GridMut> Col<&'a mut G> {
         *self.get_mut(0).unwrap() = value;
     }
 
+    /// Reverse the order of the values in the column.
+    ///
+    /// ```
+    /// # use grid::{GridMut, VecGrid};
+    /// let mut grid = VecGrid::with_store(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.col_mut(1).reverse();
+    /// assert_eq!(grid.as_slice(), &[1, 6, 3, 4, 5, 2]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        let len = self.len();
+        for y in 0..(len / 2) {
+            self.grid.swap((self.x, y), (self.x, len - 1 - y));
+        }
+    }
+
     /// Clone all values from the provided column to this one.
     #[inline]
     pub fn clone_from<G2>(&mut self, col: impl Into<Col<&'a G2>>)
@@ -0,0 +1,74 @@
+use crate::neighbors_iter::NEIGHBORS4;
+use crate::{Grid, GridMut, VecGrid};
+
+/// Label the 4-connected components of a grid. Two orthogonally adjacent cells `a` and `b`
+/// belong to the same component if `connected(a, b)` returns `true`, and a cell is only
+/// considered part of any component if `connected(cell, cell)` is `true`.
+///
+/// Returns a grid the same size as `grid` containing the component id of each cell, along
+/// with how many components were found. Ids start from `1`, and `0` is used for background
+/// cells that are not part of any component.
+///
+/// ```
+/// use grid::{label_components, GridBuf};
+///
+/// let cave = GridBuf::with_store(4, 4, [
+///     '.', '#', '#', '.',
+///     '.', '#', '.', '#',
+///     '#', '.', '#', '#',
+///     '.', '.', '#', '.',
+/// ]);
+///
+/// // diagonally touching cells are not connected
+/// let (labels, count) = label_components(&cave, |a, b| *a == '.' && a == b);
+/// assert_eq!(count, 5);
+/// assert_eq!(labels.as_slice(), &[
+///     1, 0, 0, 2,
+///     1, 0, 3, 0,
+///     0, 4, 0, 0,
+///     4, 4, 0, 5,
+/// ]);
+///
+/// // a single fully-connected component
+/// let (labels, count) = label_components(&cave, |_, _| true);
+/// assert_eq!(count, 1);
+/// assert!(labels.as_slice().iter().all(|&id| id == 1));
+/// ```
+pub fn label_components<G, F>(grid: &G, connected: F) -> (VecGrid<u32>, u32)
+where
+    G: Grid,
+    F: Fn(&G::Item, &G::Item) -> bool,
+{
+    let (w, h) = grid.size();
+    let mut labels = VecGrid::new(w, h);
+    let mut count = 0;
+    let mut stack = Vec::new();
+    for y in 0..h {
+        for x in 0..w {
+            let val = grid.get(x, y).unwrap();
+            if labels.as_slice()[y * w + x] != 0 || !connected(val, val) {
+                continue;
+            }
+            count += 1;
+            labels.set(x, y, count);
+            stack.push((x, y));
+            while let Some((x, y)) = stack.pop() {
+                let a = grid.get(x, y).unwrap();
+                for (dx, dy) in NEIGHBORS4 {
+                    let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy))
+                    else {
+                        continue;
+                    };
+                    let Some(b) = grid.get(x, y) else {
+                        continue;
+                    };
+                    if labels.as_slice()[y * w + x] == 0 && connected(b, b) && connected(a, b) {
+                        labels.set(x, y, count);
+                        stack.push((x, y));
+                    }
+                }
+            }
+        }
+    }
+    (labels, count)
+}
@@ -0,0 +1,12 @@
+/// How to sample values outside of a grid's bounds, such as when convolving.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EdgeMode {
+    /// Use the nearest value inside the grid, like [`Clamp`](crate::Clamp).
+    Clamp,
+    /// Wrap around to the other side of the grid, like [`Wrap`](crate::Wrap).
+    Wrap,
+    /// Treat values outside of the grid as zero (the item type's default value).
+    Zero,
+    /// Skip samples that fall outside of the grid, so they are never combined.
+    Skip,
+}
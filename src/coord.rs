@@ -2,6 +2,17 @@ use std::fmt::Debug;
 use std::hash::Hash;
 
 /// Wraps the inner coordinate (or coord component) in grid space.
+///
+/// ```
+/// # use grid::{Grid, Wrap};
+/// let nums = [[1, 2, 3]];
+/// assert_eq!(nums.get_at((Wrap(-1), 0)), Some(&3));
+/// assert_eq!(nums.get_at((Wrap(-4), 0)), Some(&3));
+/// assert_eq!(nums.get_at((Wrap(-5), 0)), Some(&2));
+/// assert_eq!(nums.get_at((Wrap(-6), 0)), Some(&1));
+/// assert_eq!(nums.get_at((Wrap(7), 0)), Some(&2));
+/// assert_eq!(nums.get_at((Wrap(i64::MIN), 0)), Some(&2));
+/// ```
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Wrap<C>(pub C);
@@ -31,6 +42,32 @@ pub struct ClampX<C: Coord>(pub C);
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ClampY<C: Coord>(pub C);
 
+/// Reflects the inner coordinate (or coord component) in grid space, so that it
+/// bounces back and forth across the grid's edges.
+///
+/// ```
+/// # use grid::{Grid, Reflect};
+/// let nums = [[1, 2, 3]];
+/// assert_eq!(nums.get_at((Reflect(-1), 0)), Some(&1));
+/// assert_eq!(nums.get_at((Reflect(-2), 0)), Some(&2));
+/// assert_eq!(nums.get_at((Reflect(3), 0)), Some(&3));
+/// assert_eq!(nums.get_at((Reflect(4), 0)), Some(&2));
+/// assert_eq!(nums.get_at((Reflect(6), 0)), Some(&1));
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Reflect<C>(pub C);
+
+/// Reflects the x value of the inner coordinate in grid space.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReflectX<C: Coord>(pub C);
+
+/// Reflects the y value of the inner coordinate in grid space.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReflectY<C: Coord>(pub C);
+
 /// An x or y component of a grid coordinate.
 pub trait CoordComponent: Debug + Copy + Clone + Eq + PartialEq + Ord + PartialOrd + Hash {
     /// Convert this coordinate from its normal form to a position on the grid,
@@ -62,6 +99,15 @@ macro_rules! impl_comp_unsigned {
                 len.checked_sub(1).map(|max| max.min(self.0 as usize))
             }
         }
+
+        impl CoordComponent for Reflect<$type> {
+            #[inline]
+            fn to_grid(self, len: usize) -> Option<usize> {
+                let period = (len as u128) * 2;
+                let x = (self.0 as u128).checked_rem(period)? as usize;
+                Some(if x < len { x } else { len - 1 - (x - len) })
+            }
+        }
         )*
     }
 }
@@ -83,7 +129,10 @@ macro_rules! impl_comp_signed {
         impl CoordComponent for Wrap<$type> {
             #[inline]
             fn to_grid(self, len: usize) -> Option<usize> {
-                (self.0 as usize).wrapping_add(len).checked_rem(len)
+                if len == 0 {
+                    return None;
+                }
+                Some((self.0 as i128).rem_euclid(len as i128) as usize)
             }
         }
 
@@ -96,6 +145,18 @@ macro_rules! impl_comp_signed {
                 len.checked_sub(1).map(|max| max.min(self.0 as usize))
             }
         }
+
+        impl CoordComponent for Reflect<$type> {
+            #[inline]
+            fn to_grid(self, len: usize) -> Option<usize> {
+                let period = (len as i128) * 2;
+                if period == 0 {
+                    return None;
+                }
+                let x = (self.0 as i128).rem_euclid(period) as usize;
+                Some(if x < len { x } else { len - 1 - (x - len) })
+            }
+        }
         )*
     }
 }
@@ -250,3 +311,58 @@ where
         Clamp(self.0.y())
     }
 }
+
+impl<C: Coord> Coord for Reflect<C>
+where
+    Reflect<C::X>: CoordComponent,
+    Reflect<C::Y>: CoordComponent,
+{
+    type X = Reflect<C::X>;
+    type Y = Reflect<C::Y>;
+
+    #[inline]
+    fn x(&self) -> Self::X {
+        Reflect(self.0.x())
+    }
+
+    #[inline]
+    fn y(&self) -> Self::Y {
+        Reflect(self.0.y())
+    }
+}
+
+impl<C: Coord> Coord for ReflectX<C>
+where
+    Reflect<C::X>: CoordComponent,
+{
+    type X = Reflect<C::X>;
+    type Y = C::Y;
+
+    #[inline]
+    fn x(&self) -> Self::X {
+        Reflect(self.0.x())
+    }
+
+    #[inline]
+    fn y(&self) -> Self::Y {
+        self.0.y()
+    }
+}
+
+impl<C: Coord> Coord for ReflectY<C>
+where
+    Reflect<C::Y>: CoordComponent,
+{
+    type X = C::X;
+    type Y = Reflect<C::Y>;
+
+    #[inline]
+    fn x(&self) -> Self::X {
+        self.0.x()
+    }
+
+    #[inline]
+    fn y(&self) -> Self::Y {
+        Reflect(self.0.y())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::Grid;
+use std::iter::FusedIterator;
+
+/// Iterator over the values along a diagonal of a grid, and their positions.
+#[derive(Copy, Clone)]
+pub struct DiagonalIter<GridRef> {
+    grid: GridRef,
+    x: usize,
+    y: usize,
+    len: usize,
+    anti: bool,
+}
+
+impl<'a, G: Grid> DiagonalIter<&'a G> {
+    /// Cells where `x - y == offset`, from top-left to bottom-right.
+    #[inline]
+    pub(crate) fn main(grid: &'a G, offset: isize) -> Self {
+        let (w, h) = (grid.width(), grid.height());
+        let (x, y) = if offset >= 0 {
+            (offset.unsigned_abs(), 0)
+        } else {
+            (0, offset.unsigned_abs())
+        };
+        let len = w.saturating_sub(x).min(h.saturating_sub(y));
+        Self {
+            grid,
+            x,
+            y,
+            len,
+            anti: false,
+        }
+    }
+
+    /// Cells where `x + y == width - 1 + offset`, from top-right to bottom-left.
+    #[inline]
+    pub(crate) fn anti(grid: &'a G, offset: isize) -> Self {
+        let (w, h) = (grid.width(), grid.height());
+        let (x, y, len) = match (w.checked_sub(1), offset >= 0) {
+            (Some(x), true) => {
+                let y = offset.unsigned_abs();
+                (x, y, w.min(h.saturating_sub(y)))
+            }
+            (Some(x), false) => match x.checked_sub(offset.unsigned_abs()) {
+                Some(x) => (x, 0, (x + 1).min(h)),
+                None => (0, 0, 0),
+            },
+            (None, _) => (0, 0, 0),
+        };
+        Self {
+            grid,
+            x,
+            y,
+            len,
+            anti: true,
+        }
+    }
+}
+
+impl<'a, G: Grid> Iterator for DiagonalIter<&'a G> {
+    type Item = (&'a G::Item, usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let (x, y) = (self.x, self.y);
+        self.len -= 1;
+        if self.len > 0 {
+            self.x = if self.anti { x - 1 } else { x + 1 };
+            self.y += 1;
+        }
+        Some((self.grid.get(x, y)?, x, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<G: Grid> ExactSizeIterator for DiagonalIter<&G> {}
+
+impl<G: Grid> FusedIterator for DiagonalIter<&G> {}
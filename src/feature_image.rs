@@ -0,0 +1,25 @@
+use crate::{GridBuf, VecGrid};
+use image::{ImageBuffer, Pixel};
+use std::ops::Deref;
+
+impl<P: Pixel> VecGrid<P> {
+    /// Create a grid of pixels copied from an [`ImageBuffer`], with the same width and
+    /// height as the image.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// use image::{GrayImage, Luma};
+    ///
+    /// let img = GrayImage::from_fn(3, 2, |x, y| Luma([(x + y * 3) as u8]));
+    /// let grid = VecGrid::from_image(&img);
+    /// assert_eq!(grid.size(), (3, 2));
+    /// assert_eq!(grid.get(2, 1), Some(&Luma([5])));
+    /// ```
+    pub fn from_image<C>(img: &ImageBuffer<P, C>) -> Self
+    where
+        C: Deref<Target = [P::Subpixel]>,
+    {
+        let (w, h) = img.dimensions();
+        GridBuf::with_store(w as usize, h as usize, img.pixels().copied().collect())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{GridBuf, VecGrid};
+use ndarray::{ArrayBase, Data, Ix2};
+
+impl<T: Clone> VecGrid<T> {
+    /// Create a grid by cloning the values of a 2D [ndarray](ndarray) array, where the
+    /// array's rows (its first axis) become the grid's rows. Arrays in standard layout are
+    /// copied directly, and any other layout is copied in logical row-major order.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// use ndarray::array;
+    ///
+    /// let arr = array![[1, 2, 3], [4, 5, 6]];
+    /// let grid = VecGrid::from_array2(&arr);
+    /// assert_eq!(grid.size(), (3, 2));
+    /// assert_eq!(grid.get(2, 0), Some(&3));
+    ///
+    /// let grid = VecGrid::from_array2(&arr.t());
+    /// assert_eq!(grid.size(), (2, 3));
+    /// assert_eq!(grid.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn from_array2<S>(arr: &ArrayBase<S, Ix2>) -> Self
+    where
+        S: Data<Elem = T>,
+    {
+        let (h, w) = arr.dim();
+        let store = match arr.as_slice() {
+            Some(slice) => slice.to_vec(),
+            None => arr.iter().cloned().collect(),
+        };
+        GridBuf::with_store(w, h, store)
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Grid, GridBuf};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::{Chunks, ChunksMut, ParallelSlice, ParallelSliceMut};
+
+impl<T, S> GridBuf<T, S> {
+    /// Returns a parallel iterator over the rows of the grid, as slices.
+    ///
+    /// Rows are only produced for grids with a non-zero width.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// use rayon::prelude::*;
+    ///
+    /// let grid = VecGrid::with_store(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let sums: Vec<i32> = grid.par_rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![6, 15]);
+    /// ```
+    #[inline]
+    pub fn par_rows(&self) -> Chunks<'_, T>
+    where
+        T: Sync,
+        S: AsRef<[T]>,
+    {
+        self.as_slice().par_chunks(self.width.max(1))
+    }
+
+    /// Returns a parallel iterator over the rows of the grid, as mutable slices.
+    ///
+    /// Rows are only produced for grids with a non-zero width.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut grid = VecGrid::with_store(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.par_rows_mut().enumerate().for_each(|(y, row)| {
+    ///     row.iter_mut().for_each(|val| *val *= y as i32 + 1);
+    /// });
+    /// assert_eq!(grid.as_slice(), &[1, 2, 3, 8, 10, 12]);
+    /// ```
+    #[inline]
+    pub fn par_rows_mut(&mut self) -> ChunksMut<'_, T>
+    where
+        T: Send,
+        S: AsMut<[T]>,
+    {
+        let w = self.width.max(1);
+        self.as_mut_slice().par_chunks_mut(w)
+    }
+}
+
+/// Parallel iterator over all values in a grid, and their positions.
+///
+/// Values are indexed in row-major order, so the value at `(x, y)` has the index
+/// `y * width + x`.
+#[derive(Copy, Clone)]
+pub struct ParGridIter<'a, G> {
+    grid: &'a G,
+}
+
+impl<'a, G> ParGridIter<'a, G> {
+    #[inline]
+    pub(crate) fn new(grid: &'a G) -> Self {
+        Self { grid }
+    }
+}
+
+impl<'a, G> ParallelIterator for ParGridIter<'a, G>
+where
+    G: Grid + Sync,
+    G::Item: Sync,
+{
+    type Item = (&'a G::Item, usize, usize);
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<G> IndexedParallelIterator for ParGridIter<'_, G>
+where
+    G: Grid + Sync,
+    G::Item: Sync,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.grid.width() * self.grid.height()
+    }
+
+    #[inline]
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let end = self.len();
+        callback.callback(GridProducer {
+            grid: self.grid,
+            start: 0,
+            end,
+        })
+    }
+}
+
+/// Splits a range of row-major indices into the grid between rayon's workers.
+struct GridProducer<'a, G> {
+    grid: &'a G,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, G> Producer for GridProducer<'a, G>
+where
+    G: Grid + Sync,
+    G::Item: Sync,
+{
+    type Item = (&'a G::Item, usize, usize);
+    type IntoIter = Self;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index;
+        (
+            Self {
+                grid: self.grid,
+                start: self.start,
+                end: mid,
+            },
+            Self {
+                grid: self.grid,
+                start: mid,
+                end: self.end,
+            },
+        )
+    }
+}
+
+impl<'a, G: Grid> GridProducer<'a, G> {
+    #[inline]
+    fn get(&self, i: usize) -> (&'a G::Item, usize, usize) {
+        let w = self.grid.width();
+        let (x, y) = (i % w, i / w);
+        // SAFETY: the producer's range never exceeds `width * height`, so every index maps to a
+        // position inside the grid.
+        (unsafe { self.grid.get_unchecked(x, y) }, x, y)
+    }
+}
+
+impl<'a, G: Grid> Iterator for GridProducer<'a, G> {
+    type Item = (&'a G::Item, usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(self.get(self.start - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<G: Grid> DoubleEndedIterator for GridProducer<'_, G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.get(self.end))
+    }
+}
+
+impl<G: Grid> ExactSizeIterator for GridProducer<'_, G> {}
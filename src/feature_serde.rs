@@ -1,6 +1,8 @@
-use crate::GridBuf;
-use serde::ser::SerializeStruct;
+use crate::{Grid, GridBuf, Row, RowLengthError, VecGrid};
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
 use std::marker::PhantomData;
 
 impl<T, Store: Serialize> Serialize for GridBuf<T, Store> {
@@ -17,7 +19,17 @@ impl<T, Store: Serialize> Serialize for GridBuf<T, Store> {
     }
 }
 
-impl<'de, T, S: Deserialize<'de>> Deserialize<'de> for GridBuf<T, S> {
+/// Deserializing fails if the length of `store` is not equal to `width * height`.
+///
+/// ```
+/// # use grid::VecGrid;
+/// let json = r#"{"width":2,"height":2,"store":[1,2,3,4]}"#;
+/// assert!(serde_json::from_str::<VecGrid<i32>>(json).is_ok());
+///
+/// let json = r#"{"width":2,"height":2,"store":[1,2,3]}"#;
+/// assert!(serde_json::from_str::<VecGrid<i32>>(json).is_err());
+/// ```
+impl<'de, T, S: AsRef<[T]> + Deserialize<'de>> Deserialize<'de> for GridBuf<T, S> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -33,7 +45,14 @@ impl<'de, T, S: Deserialize<'de>> Deserialize<'de> for GridBuf<T, S> {
             width,
             height,
             store,
-        } = GridBufDe::deserialize(deserializer)?;
+        } = GridBufDe::<S>::deserialize(deserializer)?;
+        let len = store.as_ref().len();
+        if width.checked_mul(height) != Some(len) {
+            return Err(D::Error::invalid_length(
+                len,
+                &"a store with a length of width * height",
+            ));
+        }
         Ok(Self {
             width,
             height,
@@ -42,3 +61,167 @@ impl<'de, T, S: Deserialize<'de>> Deserialize<'de> for GridBuf<T, S> {
         })
     }
 }
+
+/// A wrapper that serializes a grid compactly as a list of rows, such as
+/// `[[0,1,2],[3,4,5]]`. When deserializing, the width is taken from the rows' length
+/// and the height from the number of rows, and rows of unequal length are an error.
+///
+/// ```
+/// # use grid::{CompactGrid, VecGrid};
+/// let grid = VecGrid::with_store(3, 2, vec![0, 1, 2, 3, 4, 5]);
+/// let json = serde_json::to_string(&CompactGrid(&grid)).unwrap();
+/// assert_eq!(json, "[[0,1,2],[3,4,5]]");
+///
+/// let CompactGrid(de): CompactGrid<VecGrid<i32>> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(de, grid);
+///
+/// let err = serde_json::from_str::<CompactGrid<VecGrid<i32>>>("[[0,1],[2]]").unwrap_err();
+/// assert!(err.to_string().starts_with("row 1 has a length of 1, expected 2"));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CompactGrid<G>(pub G);
+
+impl<T: Serialize, Store: AsRef<[T]>> Serialize for CompactGrid<GridBuf<T, Store>> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_rows(&self.0, serializer)
+    }
+}
+
+impl<G: Grid> Serialize for CompactGrid<&G>
+where
+    G::Item: Serialize,
+{
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_rows(self.0, serializer)
+    }
+}
+
+fn serialize_rows<G: Grid, S: Serializer>(grid: &G, serializer: S) -> Result<S::Ok, S::Error>
+where
+    G::Item: Serialize,
+{
+    struct RowSer<'a, G>(Row<&'a G>);
+
+    impl<G: Grid> Serialize for RowSer<'_, G>
+    where
+        G::Item: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.0.iter())
+        }
+    }
+
+    let mut seq = serializer.serialize_seq(Some(grid.height()))?;
+    for y in 0..grid.height() {
+        seq.serialize_element(&RowSer(grid.row(y)))?;
+    }
+    seq.end()
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for CompactGrid<VecGrid<T>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RowsVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for RowsVisitor<T> {
+            type Value = VecGrid<T>;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a list of equal-length rows")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut store = Vec::new();
+                let mut width = 0;
+                let mut height = 0;
+                while let Some(row) = seq.next_element::<Vec<T>>()? {
+                    if height == 0 {
+                        width = row.len();
+                    } else if row.len() != width {
+                        return Err(A::Error::custom(RowLengthError {
+                            row: height,
+                            expected: width,
+                            found: row.len(),
+                        }));
+                    }
+                    store.extend(row);
+                    height += 1;
+                }
+                Ok(GridBuf::with_store(width, height, store))
+            }
+        }
+
+        deserializer
+            .deserialize_seq(RowsVisitor(PhantomData))
+            .map(CompactGrid)
+    }
+}
+
+/// Serialize and deserialize a [`GridBuf`] as a list of rows, in the same format as
+/// [`CompactGrid`]. Use this with serde's `with` attribute to store a grid field in the
+/// compact format, while the default remains `{width, height, store}`.
+///
+/// ```
+/// # use grid::VecGrid;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Level {
+///     #[serde(with = "grid::serde_rows")]
+///     tiles: VecGrid<u8>,
+/// }
+///
+/// let level = Level {
+///     tiles: VecGrid::with_store(3, 2, vec![1, 0, 1, 0, 1, 0]),
+/// };
+/// let json = serde_json::to_string(&level).unwrap();
+/// assert_eq!(json, r#"{"tiles":[[1,0,1],[0,1,0]]}"#);
+///
+/// let de: Level = serde_json::from_str(&json).unwrap();
+/// assert_eq!(de.tiles, level.tiles);
+/// ```
+pub mod serde_rows {
+    use super::CompactGrid;
+    use crate::{GridBuf, VecGrid};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialize a grid as a list of rows.
+    #[inline]
+    pub fn serialize<T, Store, S>(
+        grid: &GridBuf<T, Store>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        Store: AsRef<[T]>,
+        S: Serializer,
+    {
+        CompactGrid(grid).serialize(serializer)
+    }
+
+    /// Deserialize a grid from a list of equal-length rows.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<VecGrid<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        CompactGrid::deserialize(deserializer).map(|CompactGrid(grid)| grid)
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{Grid, GridMut};
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+
+/// A grid mirrored horizontally, so that its x-axis is reversed.
+#[repr(C)]
+#[derive(Clone)]
+pub struct FlipX<GridRef> {
+    grid: GridRef,
+}
+
+impl<GridRef> FlipX<GridRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef) -> Self {
+        Self { grid }
+    }
+}
+
+impl<'a, G> Deref for FlipX<&'a mut G> {
+    type Target = FlipX<&'a G>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<'a, G> From<FlipX<&'a mut G>> for FlipX<&'a G> {
+    #[inline]
+    fn from(FlipX { grid }: FlipX<&'a mut G>) -> Self {
+        Self { grid }
+    }
+}
+
+impl<G: Grid> Grid for FlipX<&G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        let x = self.grid.width().checked_sub(x)?.checked_sub(1)?;
+        self.grid.get(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        self.grid.get_unchecked(self.grid.width() - 1 - x, y)
+    }
+
+    #[inline]
+    fn row_slice(&self, _y: usize) -> Option<&[Self::Item]> {
+        None
+    }
+}
+
+impl<G: Grid> Grid for FlipX<&mut G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        let x = self.grid.width().checked_sub(x)?.checked_sub(1)?;
+        self.grid.get(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        self.grid.get_unchecked(self.grid.width() - 1 - x, y)
+    }
+
+    #[inline]
+    fn row_slice(&self, _y: usize) -> Option<&[Self::Item]> {
+        None
+    }
+}
+
+impl<G: GridMut> GridMut for FlipX<&mut G> {
+    type RootMut = Self;
+
+    #[inline]
+    fn root_mut(&mut self) -> &mut Self::RootMut {
+        self
+    }
+
+    #[inline]
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Item> {
+        let x = self.grid.width().checked_sub(x)?.checked_sub(1)?;
+        self.grid.get_mut(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut Self::Item {
+        let x = self.grid.width() - 1 - x;
+        self.grid.get_unchecked_mut(x, y)
+    }
+
+    #[inline]
+    fn row_slice_mut(&mut self, _y: usize) -> Option<&mut [Self::Item]> {
+        None
+    }
+}
+
+impl<G: Grid> Debug for FlipX<&G>
+where
+    G::Item: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.debug_fmt(f)
+    }
+}
+
+/// A grid mirrored vertically, so that its y-axis is reversed.
+#[repr(C)]
+#[derive(Clone)]
+pub struct FlipY<GridRef> {
+    grid: GridRef,
+}
+
+impl<GridRef> FlipY<GridRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef) -> Self {
+        Self { grid }
+    }
+}
+
+impl<'a, G> Deref for FlipY<&'a mut G> {
+    type Target = FlipY<&'a G>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<'a, G> From<FlipY<&'a mut G>> for FlipY<&'a G> {
+    #[inline]
+    fn from(FlipY { grid }: FlipY<&'a mut G>) -> Self {
+        Self { grid }
+    }
+}
+
+impl<G: Grid> Grid for FlipY<&G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        let y = self.grid.height().checked_sub(y)?.checked_sub(1)?;
+        self.grid.get(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        self.grid.get_unchecked(x, self.grid.height() - 1 - y)
+    }
+
+    #[inline]
+    fn row_slice(&self, y: usize) -> Option<&[Self::Item]> {
+        let y = self.grid.height().checked_sub(y)?.checked_sub(1)?;
+        self.grid.row_slice(y)
+    }
+}
+
+impl<G: Grid> Grid for FlipY<&mut G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        let y = self.grid.height().checked_sub(y)?.checked_sub(1)?;
+        self.grid.get(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        self.grid.get_unchecked(x, self.grid.height() - 1 - y)
+    }
+
+    #[inline]
+    fn row_slice(&self, y: usize) -> Option<&[Self::Item]> {
+        let y = self.grid.height().checked_sub(y)?.checked_sub(1)?;
+        self.grid.row_slice(y)
+    }
+}
+
+impl<G: GridMut> GridMut for FlipY<&mut G> {
+    type RootMut = Self;
+
+    #[inline]
+    fn root_mut(&mut self) -> &mut Self::RootMut {
+        self
+    }
+
+    #[inline]
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Item> {
+        let y = self.grid.height().checked_sub(y)?.checked_sub(1)?;
+        self.grid.get_mut(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut Self::Item {
+        let y = self.grid.height() - 1 - y;
+        self.grid.get_unchecked_mut(x, y)
+    }
+
+    #[inline]
+    fn row_slice_mut(&mut self, y: usize) -> Option<&mut [Self::Item]> {
+        let y = self.grid.height().checked_sub(y)?.checked_sub(1)?;
+        self.grid.row_slice_mut(y)
+    }
+}
+
+impl<G: Grid> Debug for FlipY<&G>
+where
+    G::Item: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.debug_fmt(f)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::neighbors_iter::NEIGHBORS4;
+use crate::GridMut;
+
+/// Replace the 4-connected region of cells that are equal to the value at `(x, y)` with
+/// `value`. Does nothing if `(x, y)` is out of bounds or already equal to `value`.
+///
+/// ```
+/// use grid::{flood_fill, GridBuf};
+///
+/// let mut map = GridBuf::with_store(5, 4, vec![
+///     '.', '.', '#', '.', '.',
+///     '.', '.', '#', '.', '.',
+///     '#', '#', '#', '.', '.',
+///     '.', '.', '.', '.', '.',
+/// ]);
+///
+/// flood_fill(&mut map, 0, 0, 'x');
+/// assert_eq!(map.as_slice(), &[
+///     'x', 'x', '#', '.', '.',
+///     'x', 'x', '#', '.', '.',
+///     '#', '#', '#', '.', '.',
+///     '.', '.', '.', '.', '.',
+/// ]);
+///
+/// flood_fill(&mut map, 4, 0, 'o');
+/// assert_eq!(map.as_slice(), &[
+///     'x', 'x', '#', 'o', 'o',
+///     'x', 'x', '#', 'o', 'o',
+///     '#', '#', '#', 'o', 'o',
+///     'o', 'o', 'o', 'o', 'o',
+/// ]);
+/// ```
+pub fn flood_fill<G>(grid: &mut G, x: usize, y: usize, value: G::Item)
+where
+    G: GridMut,
+    G::Item: Clone + PartialEq,
+{
+    let Some(old) = grid.get(x, y).cloned() else {
+        return;
+    };
+    if old != value {
+        flood_fill_with(grid, x, y, value, |val| *val == old);
+    }
+}
+
+/// Replace the 4-connected region of cells starting at `(x, y)` that satisfy `pred` with
+/// `value`. Does nothing if `(x, y)` is out of bounds or does not satisfy `pred`.
+///
+/// ```
+/// use grid::{flood_fill_with, GridBuf};
+///
+/// let mut heights = GridBuf::with_store(4, 3, vec![
+///     1, 2, 9, 1,
+///     3, 9, 9, 2,
+///     2, 1, 9, 1,
+/// ]);
+///
+/// flood_fill_with(&mut heights, 0, 0, 0, |&h| h < 5);
+/// assert_eq!(heights.as_slice(), &[
+///     0, 0, 9, 1,
+///     0, 9, 9, 2,
+///     0, 0, 9, 1,
+/// ]);
+/// ```
+pub fn flood_fill_with<G, F>(grid: &mut G, x: usize, y: usize, value: G::Item, mut pred: F)
+where
+    G: GridMut,
+    G::Item: Clone,
+    F: FnMut(&G::Item) -> bool,
+{
+    if !grid.get(x, y).is_some_and(&mut pred) {
+        return;
+    }
+    let (w, h) = grid.size();
+    let mut visited = vec![false; grid.area()];
+    let mut stack = vec![(x, y)];
+    visited[y * w + x] = true;
+    while let Some((x, y)) = stack.pop() {
+        grid.set(x, y, value.clone());
+        for (dx, dy) in NEIGHBORS4 {
+            let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+                continue;
+            };
+            if x < w && y < h && !visited[y * w + x] && pred(grid.get(x, y).unwrap()) {
+                visited[y * w + x] = true;
+                stack.push((x, y));
+            }
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use crate::cols_iter::ColsIter;
+use crate::neighbors_iter::{NEIGHBORS4, NEIGHBORS8};
 use crate::{
-    ArrGrid, Col, Coord, CoordComponent, GridBuf, GridIter, GridMut, Row, RowsIter, VecGrid, View,
+    ArrGrid, Clamp, Col, Coord, CoordComponent, DiagonalIter, EdgeMode, FlipX, FlipY, GridBuf,
+    GridIter, GridMut, NeighborsIter, Rotate90, Row, RowsIter, TilesIter, Torus, Transpose,
+    VecGrid, View, ViewsIter, WindowsIter, Wrap, ZipIter,
 };
-use std::fmt::{Debug, Write};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Write};
+use std::hash::{Hash, Hasher};
+use std::ops::{Bound, RangeBounds};
 
 /// A type representing an immutable 2D array.
 pub trait Grid {
@@ -173,6 +179,19 @@ pub trait Grid {
 
     /// Returns a reference to the value stored at the provided coordinate in the grid,
     /// or `None` if the provided coordinate is out of bounds.
+    ///
+    /// ```
+    /// # use grid::{Clamp, Grid, VecGrid, Wrap};
+    /// let nums = VecGrid::with_store(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// assert_eq!(nums.get_at((1, 2)), Some(&8));
+    /// assert_eq!(nums.get_at((-1, 0)), None);
+    /// assert_eq!(nums.get_at(Wrap((-1, -1))), Some(&9));
+    /// assert_eq!(nums.get_at(Wrap((3, -2))), Some(&4));
+    /// assert_eq!(nums.get_at(Wrap((-4, -8))), Some(&6));
+    /// assert_eq!(nums.get_at(Clamp((-1, -1))), Some(&1));
+    /// assert_eq!(nums.get_at(Clamp((5, 5))), Some(&9));
+    /// ```
     #[inline]
     fn get_at(&self, coord: impl Coord) -> Option<&Self::Item> {
         self.get(
@@ -183,6 +202,14 @@ pub trait Grid {
 
     /// Returns a reference to the value stored at the provided coordinate in the grid,
     /// skipping any bounds checks.
+    ///
+    /// For a safe alternative, see [`get_at`](Self::get_at).
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds coord is *[undefined behavior]*
+    /// even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     #[inline]
     unsafe fn get_unchecked_at(&self, coord: impl Coord) -> &Self::Item {
         self.get_unchecked(
@@ -191,6 +218,190 @@ pub trait Grid {
         )
     }
 
+    /// Returns a reference to the value at `(x, y)`, wrapping coordinates that are out of
+    /// bounds around to the other side of the grid. Panics if the grid is empty.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    ///
+    /// assert_eq!(nums.get_wrapped(-1, 0), &3);
+    /// assert_eq!(nums.get_wrapped(4, -3), &5);
+    /// ```
+    #[inline]
+    fn get_wrapped(&self, x: i32, y: i32) -> &Self::Item {
+        self.get_at(Wrap((x, y)))
+            .expect("cannot sample an empty grid")
+    }
+
+    /// Returns a reference to the value at `(x, y)`, clamping coordinates that are out of
+    /// bounds to the nearest edge of the grid. Panics if the grid is empty.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    ///
+    /// assert_eq!(nums.get_clamped(-1, 0), &1);
+    /// assert_eq!(nums.get_clamped(4, 1), &6);
+    /// ```
+    #[inline]
+    fn get_clamped(&self, x: i32, y: i32) -> &Self::Item {
+        self.get_at(Clamp((x, y)))
+            .expect("cannot sample an empty grid")
+    }
+
+    /// Returns references to the 8 values surrounding `(x, y)`, in the order N, NE, E, SE,
+    /// S, SW, W, NW. Neighbors that are out of bounds are `None`.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ];
+    ///
+    /// assert_eq!(nums.neighbors(1, 1), [
+    ///     Some(&2), Some(&3), Some(&6), Some(&9),
+    ///     Some(&8), Some(&7), Some(&4), Some(&1),
+    /// ]);
+    /// assert_eq!(nums.neighbors(0, 0), [
+    ///     None, None, Some(&2), Some(&5),
+    ///     Some(&4), None, None, None,
+    /// ]);
+    /// ```
+    #[inline]
+    fn neighbors(&self, x: usize, y: usize) -> [Option<&Self::Item>; 8] {
+        NEIGHBORS8.map(|(dx, dy)| self.get(x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+    }
+
+    /// Like [`neighbors`](Self::neighbors), but neighbors that are out of bounds [`Wrap`]
+    /// around to the other side of the grid, so the edges of the grid connect toroidally.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ];
+    ///
+    /// assert_eq!(nums.neighbors_wrapping(0, 0), [
+    ///     Some(&7), Some(&8), Some(&2), Some(&5),
+    ///     Some(&4), Some(&6), Some(&3), Some(&9),
+    /// ]);
+    /// ```
+    #[inline]
+    fn neighbors_wrapping(&self, x: usize, y: usize) -> [Option<&Self::Item>; 8] {
+        NEIGHBORS8.map(|(dx, dy)| self.get_at(Wrap((x as isize + dx, y as isize + dy))))
+    }
+
+    /// Iterate over the 4 orthogonal neighbors of `(x, y)` that are in bounds, with their
+    /// positions. They are yielded in the order N, E, S, W, skipping any that are out of bounds.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ];
+    ///
+    /// let center: Vec<_> = nums.neighbors4(1, 1).collect();
+    /// assert_eq!(center, vec![(&2, 1, 0), (&6, 2, 1), (&8, 1, 2), (&4, 0, 1)]);
+    ///
+    /// assert_eq!(nums.neighbors4(1, 0).count(), 3);
+    /// assert_eq!(nums.neighbors4(2, 2).count(), 2);
+    /// ```
+    #[inline]
+    fn neighbors4(&self, x: usize, y: usize) -> NeighborsIter<&Self> {
+        NeighborsIter::new(self, x, y, &NEIGHBORS4)
+    }
+
+    /// Iterate over the 8 surrounding neighbors of `(x, y)` that are in bounds, with their
+    /// positions. They are yielded in the order N, NE, E, SE, S, SW, W, NW, skipping any that
+    /// are out of bounds.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ];
+    ///
+    /// let corner: Vec<_> = nums.neighbors8(0, 0).collect();
+    /// assert_eq!(corner, vec![(&2, 1, 0), (&5, 1, 1), (&4, 0, 1)]);
+    ///
+    /// assert_eq!(nums.neighbors8(1, 0).count(), 5);
+    /// assert_eq!(nums.neighbors8(1, 1).count(), 8);
+    /// ```
+    #[inline]
+    fn neighbors8(&self, x: usize, y: usize) -> NeighborsIter<&Self> {
+        NeighborsIter::new(self, x, y, &NEIGHBORS8)
+    }
+
+    /// Count how many of the 8 surrounding neighbors of `(x, y)` are in bounds and satisfy
+    /// the predicate, like the numbers in minesweeper or the rules of the Game of Life.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let mines = [
+    ///     [true, false, false],
+    ///     [false, false, true],
+    ///     [true, true, false],
+    /// ];
+    ///
+    /// assert_eq!(mines.count_neighbors(1, 1, |&m| m), 4);
+    /// assert_eq!(mines.count_neighbors(0, 0, |&m| m), 0);
+    /// assert_eq!(mines.count_neighbors(2, 2, |&m| m), 2);
+    /// assert_eq!(mines.count_neighbors(0, 0, |_| true), 3);
+    /// assert_eq!(mines.count_neighbors(1, 0, |_| true), 5);
+    /// ```
+    #[inline]
+    fn count_neighbors<F>(&self, x: usize, y: usize, mut pred: F) -> u8
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.neighbors(x, y)
+            .into_iter()
+            .flatten()
+            .filter(|val| pred(val))
+            .count() as u8
+    }
+
+    /// Like [`count_neighbors`](Self::count_neighbors), but only the 4 orthogonal neighbors
+    /// of `(x, y)` are counted.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let walls = [
+    ///     [true, true, false],
+    ///     [false, false, true],
+    /// ];
+    ///
+    /// assert_eq!(walls.count_neighbors4(1, 1, |&w| w), 2);
+    /// assert_eq!(walls.count_neighbors4(0, 0, |_| true), 2);
+    /// assert_eq!(walls.count_neighbors4(1, 0, |_| true), 3);
+    /// ```
+    #[inline]
+    fn count_neighbors4<F>(&self, x: usize, y: usize, mut pred: F) -> u8
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        NEIGHBORS4
+            .iter()
+            .filter_map(|&(dx, dy)| self.get(x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+            .filter(|val| pred(val))
+            .count() as u8
+    }
+
     /// Returns row `y` of the grid as a slice if it is able to do so. Algorithms that work
     /// on large portions of the grid may use this to look for performance gain. For example,
     /// [`Row::draw_copied`] uses this internally to call [`copy_from_slice`] when possible,
@@ -203,197 +414,1439 @@ pub trait Grid {
     /// ```
     /// # use grid::Grid;
     /// let nums = [
-    ///     [0, 1, 2],
-    ///     [3, 4, 5],
-    ///     [6, 7, 8],
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    ///     [6, 7, 8],
+    /// ];
+    ///
+    /// assert_eq!(nums.row_slice(0), Some([0, 1, 2].as_slice()));
+    /// assert_eq!(nums.row_slice(1), Some([3, 4, 5].as_slice()));
+    /// assert_eq!(nums.row_slice(2), Some([6, 7, 8].as_slice()));
+    /// assert_eq!(nums.row_slice(3), None);
+    /// ```
+    fn row_slice(&self, y: usize) -> Option<&[Self::Item]>;
+
+    /// Returns the entire grid as a single slice in row-major order if it is able to do so.
+    /// Like [`row_slice`](Self::row_slice), algorithms that work on the whole grid may use
+    /// this to process every value in one go. By default this returns `None`.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// assert_eq!(nums.contiguous_slice(), Some([0, 1, 2, 3, 4, 5].as_slice()));
+    /// assert_eq!(nums.view(0, 1, 3, 1).contiguous_slice(), Some([3, 4, 5].as_slice()));
+    /// assert_eq!(nums.view(1, 0, 2, 2).contiguous_slice(), None);
+    /// ```
+    #[inline]
+    fn contiguous_slice(&self) -> Option<&[Self::Item]> {
+        None
+    }
+
+    /// Returns row `y` of the grid as a slice if it is able to do so. This variation
+    /// can take signed integers, or y-values in a [`Wrap`](crate::Wrap) or
+    /// [`Clamp`](crate::Clamp).
+    #[inline]
+    fn row_slice_at(&self, y: impl CoordComponent) -> Option<&[Self::Item]> {
+        self.row_slice(y.to_grid(self.height())?)
+    }
+
+    /// Returns true if both grids are the same size.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2],
+    ///     [3, 4],
+    /// ];
+    ///
+    /// assert!(nums.same_size(&[
+    ///     [0, 0],
+    ///     [0, 0],
+    /// ]));
+    /// assert!(!nums.same_size(&[
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ]));
+    /// assert!(!nums.same_size(&[
+    ///     [0, 0],
+    ///     [0, 0],
+    ///     [0, 0],
+    /// ]));
+    /// ```
+    #[inline]
+    fn same_size<G2: Grid>(&self, other: &G2) -> bool {
+        self.width() == other.width() && self.height() == other.height()
+    }
+
+    /// Get an immutable [`View`] into this grid, or `None` if the provided region is
+    /// out of bounds.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    ///     [6, 7, 8],
+    /// ];
+    ///
+    /// assert_eq!(nums.get(0, 0), Some(&0));
+    /// assert_eq!(nums.get(1, 1), Some(&4));
+    ///
+    /// let view = nums.try_view(1, 1, 2, 2).unwrap();
+    /// assert_eq!(view.get(0, 0), Some(&4));
+    /// assert_eq!(view.get(1, 1), Some(&8));
+    ///
+    /// assert!(nums.try_view(2, 2, 5, 5).is_none());
+    /// assert!(nums.try_view(usize::MAX, 0, 2, 1).is_none());
+    /// assert!(nums.try_view(0, 1, 1, usize::MAX).is_none());
+    /// ```
+    #[inline]
+    fn try_view(&self, x: usize, y: usize, w: usize, h: usize) -> Option<View<&Self::Root>> {
+        if x.checked_add(w)? <= self.width() && y.checked_add(h)? <= self.height() {
+            let x = self.root_x() + x;
+            let y = self.root_y() + y;
+            Some(View::new(self.root(), x, y, w, h))
+        } else {
+            None
+        }
+    }
+
+    /// Get an immutable [`View`] into this grid. Panicks if the provided region is out
+    /// of bounds.
+    #[inline]
+    fn view(&self, x: usize, y: usize, w: usize, h: usize) -> View<&Self::Root> {
+        self.try_view(x, y, w, h)
+            .expect("view does not overlap grid's bounds")
+    }
+
+    /// Iterate over the grid split into non-overlapping `tw × th` tiles, in row-major
+    /// order. If the grid's size is not a multiple of the tile size, the tiles along the
+    /// right and bottom edges are clipped to fit. Panics if `tw` or `th` is zero.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2, 3, 4],
+    ///     [5, 6, 7, 8, 9],
+    /// ];
+    ///
+    /// let sizes: Vec<_> = nums.tiles(2, 2).map(|tile| tile.size()).collect();
+    /// assert_eq!(sizes, vec![(2, 2), (2, 2), (1, 2)]);
+    ///
+    /// let last = nums.tiles(2, 2).last().unwrap();
+    /// assert_eq!(last.get(0, 1), Some(&9));
+    /// ```
+    #[inline]
+    fn tiles(&self, tw: usize, th: usize) -> TilesIter<&Self> {
+        TilesIter::new(self, tw, th)
+    }
+
+    /// Iterate over the grid split into full-width bands that are `rows_per_band` rows
+    /// tall, from top to bottom. If the height is not a multiple of `rows_per_band`, the
+    /// last band is shorter. Panics if `rows_per_band` is zero.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    ///     [6, 7, 8],
+    /// ];
+    ///
+    /// let sizes: Vec<_> = nums.row_bands(2).map(|band| band.size()).collect();
+    /// assert_eq!(sizes, vec![(3, 2), (3, 1)]);
+    ///
+    /// let last = nums.row_bands(2).last().unwrap();
+    /// assert_eq!(last.get(2, 0), Some(&8));
+    /// ```
+    #[inline]
+    fn row_bands(&self, rows_per_band: usize) -> TilesIter<&Self> {
+        TilesIter::new(self, self.width().max(1), rows_per_band)
+    }
+
+    /// Iterate over the grid divided into `cols × rows` roughly-equal views, in row-major
+    /// order. If the grid's size does not divide evenly, the earlier columns and rows of
+    /// views are one cell larger than the later ones. If `cols` or `rows` is zero, nothing
+    /// is yielded.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2, 3, 4],
+    ///     [5, 6, 7, 8, 9],
+    /// ];
+    ///
+    /// let sizes: Vec<_> = nums.views(2, 2).map(|view| view.size()).collect();
+    /// assert_eq!(sizes, vec![(3, 1), (2, 1), (3, 1), (2, 1)]);
+    ///
+    /// let sums: Vec<i32> = nums.views(3, 1).map(|view| view.iter().map(|(n, _, _)| n).sum()).collect();
+    /// assert_eq!(sums, vec![12, 20, 13]);
+    ///
+    /// assert_eq!(nums.views(0, 2).count(), 0);
+    /// ```
+    #[inline]
+    fn views(&self, cols: usize, rows: usize) -> ViewsIter<&Self> {
+        ViewsIter::new(self, cols, rows)
+    }
+
+    /// Iterate over every overlapping `w × h` window of the grid, stepping one cell at a
+    /// time along the x-axis and then the y-axis, like a 2D [`slice::windows`]. This yields
+    /// `(width - w + 1) * (height - h + 1)` windows in total. If either dimension of the
+    /// window is zero, or larger than the grid, nothing is yielded.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2, 3],
+    ///     [4, 5, 6, 7],
+    ///     [8, 9, 10, 11],
+    /// ];
+    ///
+    /// let sums: Vec<i32> = nums
+    ///     .windows(3, 2)
+    ///     .map(|win| win.iter().map(|(n, _, _)| n).sum())
+    ///     .collect();
+    /// assert_eq!(sums, vec![18, 24, 42, 48]);
+    ///
+    /// let origins: Vec<_> = nums.windows(2, 2).map(|win| (win.root_x(), win.root_y())).collect();
+    /// assert_eq!(origins, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    ///
+    /// assert_eq!([[0; 5]; 5].windows(3, 3).len(), 9);
+    /// assert_eq!(nums.windows(0, 1).count(), 0);
+    /// assert_eq!(nums.windows(5, 1).count(), 0);
+    /// ```
+    #[inline]
+    fn windows(&self, w: usize, h: usize) -> WindowsIter<&Self> {
+        WindowsIter::new(self, w, h)
+    }
+
+    /// Get an immutable [`Transpose`] of this grid, which swaps its x and y axes without
+    /// copying anything. Its rows are this grid's columns, and vice versa.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// let trans = nums.transpose_view();
+    /// assert_eq!(trans.size(), (2, 3));
+    /// assert_eq!(trans.get(1, 0), Some(&3));
+    /// assert_eq!(trans.get(0, 2), Some(&2));
+    ///
+    /// let cols: Vec<Vec<i32>> = trans
+    ///     .rows()
+    ///     .map(|row| row.into_iter().copied().collect())
+    ///     .collect();
+    /// assert_eq!(cols, vec![vec![0, 3], vec![1, 4], vec![2, 5]]);
+    /// ```
+    #[inline]
+    fn transpose_view(&self) -> Transpose<&Self> {
+        Transpose::new(self)
+    }
+
+    /// Get an immutable [`Torus`] of this grid, whose edges wrap around to the opposite
+    /// side without copying anything. The torus has the same width and height as this grid.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ];
+    ///
+    /// let torus = nums.torus_view();
+    /// assert_eq!(torus.size(), (3, 3));
+    /// assert_eq!(torus.get(4, 3), Some(&2));
+    /// assert_eq!(torus.count_neighbors(0, 0, |&n| n > 6), 3);
+    /// ```
+    #[inline]
+    fn torus_view(&self) -> Torus<&Self> {
+        Torus::new(self)
+    }
+
+    /// Get an immutable [`FlipX`] of this grid, which mirrors it horizontally without
+    /// copying anything.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// let flip = nums.flip_x_view();
+    /// assert_eq!(flip.get(0, 0), Some(&2));
+    /// assert_eq!(flip.get(2, 1), Some(&3));
+    /// assert_eq!(flip.get(3, 0), None);
+    /// ```
+    #[inline]
+    fn flip_x_view(&self) -> FlipX<&Self> {
+        FlipX::new(self)
+    }
+
+    /// Get an immutable [`FlipY`] of this grid, which mirrors it vertically without
+    /// copying anything.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// let flip = nums.flip_y_view();
+    /// assert_eq!(flip.get(0, 0), Some(&3));
+    /// assert_eq!(flip.get(2, 1), Some(&2));
+    /// assert_eq!(flip.row_slice(0), Some([3, 4, 5].as_slice()));
+    /// ```
+    #[inline]
+    fn flip_y_view(&self) -> FlipY<&Self> {
+        FlipY::new(self)
+    }
+
+    /// Get an immutable [`Rotate90`] of this grid, which rotates it 90° clockwise
+    /// without copying anything.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// let rot = nums.rotate_cw_view();
+    /// assert_eq!(rot.size(), (2, 3));
+    /// assert_eq!(rot.to_vec_grid().as_slice(), &[
+    ///     3, 0,
+    ///     4, 1,
+    ///     5, 2,
+    /// ]);
+    /// ```
+    #[inline]
+    fn rotate_cw_view(&self) -> Rotate90<&Self> {
+        Rotate90::new(self, 1)
+    }
+
+    /// Get an immutable [`Rotate90`] of this grid, which rotates it 90° counter-clockwise
+    /// without copying anything.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// let rot = nums.rotate_ccw_view();
+    /// assert_eq!(rot.size(), (2, 3));
+    /// assert_eq!(rot.to_vec_grid().as_slice(), &[
+    ///     2, 5,
+    ///     1, 4,
+    ///     0, 3,
+    /// ]);
+    /// ```
+    #[inline]
+    fn rotate_ccw_view(&self) -> Rotate90<&Self> {
+        Rotate90::new(self, 3)
+    }
+
+    /// Get an immutable [`Rotate90`] of this grid, which rotates it 180° without
+    /// copying anything.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// let rot = nums.rotate_180_view();
+    /// assert_eq!(rot.size(), (3, 2));
+    /// assert_eq!(rot.to_vec_grid().as_slice(), &[
+    ///     5, 4, 3,
+    ///     2, 1, 0,
+    /// ]);
+    /// ```
+    #[inline]
+    fn rotate_180_view(&self) -> Rotate90<&Self> {
+        Rotate90::new(self, 2)
+    }
+
+    /// Create a [`GridBuf`] using the provided storage and clone this entire
+    /// grid into it. The resulting grid will be the same size as this one.
+    #[inline]
+    fn to_grid_buf<S>(&self, store: S) -> GridBuf<Self::Item, S>
+    where
+        S: AsRef<[Self::Item]> + AsMut<[Self::Item]>,
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        let mut buf = GridBuf::with_store(self.width(), self.height(), store);
+        buf.draw_cloned(self);
+        buf
+    }
+
+    /// Create a stack-allocated [`GridBuf`], using an `N`-sized array for storage,
+    /// and clone this entire grid into it. Panics if `N` is not exactly the area
+    /// of the grid (`width * height`).
+    #[inline]
+    fn to_arr_grid<const N: usize>(&self) -> ArrGrid<Self::Item, N>
+    where
+        Self::Item: Default + Clone,
+        Self: Sized,
+    {
+        assert_eq!(self.width() * self.height(), N);
+        self.try_to_arr_grid().unwrap()
+    }
+
+    /// Create a stack-allocated [`GridBuf`], using an `N`-sized array for storage,
+    /// and clone this entire grid into it, or return `None` if `N` is not exactly
+    /// the area of the grid (`width * height`).
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    ///
+    /// let arr = nums.try_to_arr_grid::<6>().unwrap();
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// assert!(nums.try_to_arr_grid::<4>().is_none());
+    /// ```
+    #[inline]
+    fn try_to_arr_grid<const N: usize>(&self) -> Option<ArrGrid<Self::Item, N>>
+    where
+        Self::Item: Default + Clone,
+        Self: Sized,
+    {
+        if self.width().checked_mul(self.height())? != N {
+            return None;
+        }
+        let mut arr = std::array::from_fn(|_| Self::Item::default());
+        for (dst, src) in arr.chunks_exact_mut(self.width().max(1)).zip(self.rows()) {
+            if let Some(src) = src.as_slice() {
+                dst.clone_from_slice(src);
+            } else {
+                for (dst, src) in dst.iter_mut().zip(&src) {
+                    *dst = src.clone();
+                }
+            }
+        }
+        Some(GridBuf::with_store(self.width(), self.height(), arr))
+    }
+
+    /// Create an [`ImageBuffer`](image::ImageBuffer) with the same size as this grid, and
+    /// copy each pixel value from the grid into it. Panics if the width or height does not
+    /// fit in a `u32`.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut, VecGrid};
+    /// use image::Rgba;
+    ///
+    /// let mut grid = VecGrid::new_with(4, 3, || Rgba([0, 0, 0, 255]));
+    /// grid.view_mut(1, 1, 2, 1).fill(Rgba([255, 0, 0, 255]));
+    ///
+    /// let img = grid.to_image();
+    /// assert_eq!(img.dimensions(), (4, 3));
+    /// assert_eq!(img.get_pixel(2, 1), &Rgba([255, 0, 0, 255]));
+    /// assert_eq!(img.get_pixel(3, 1), &Rgba([0, 0, 0, 255]));
+    /// ```
+    #[cfg(feature = "image")]
+    fn to_image(
+        &self,
+    ) -> image::ImageBuffer<Self::Item, Vec<<Self::Item as image::Pixel>::Subpixel>>
+    where
+        Self::Item: image::Pixel,
+    {
+        let w = u32::try_from(self.width()).expect("grid is too wide for an image");
+        let h = u32::try_from(self.height()).expect("grid is too tall for an image");
+        // SAFETY: `x` and `y` come from the image, which has the same size as this grid.
+        image::ImageBuffer::from_fn(w, h, |x, y| unsafe {
+            *self.get_unchecked(x as usize, y as usize)
+        })
+    }
+
+    /// Create a 2D [ndarray](ndarray) array by cloning the values of this grid. Its shape is
+    /// `(height, width)`, so the value at `(x, y)` is at index `[y, x]` in the array.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    ///
+    /// let arr = nums.to_array2();
+    /// assert_eq!(arr.dim(), (2, 3));
+    /// assert_eq!(arr[[1, 0]], 4);
+    /// assert_eq!(arr, ndarray::array![[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    #[cfg(feature = "ndarray")]
+    fn to_array2(&self) -> ndarray::Array2<Self::Item>
+    where
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        let (w, h) = (self.width(), self.height());
+        ndarray::Array2::from_shape_vec((h, w), self.to_vec_grid().to_store()).unwrap()
+    }
+
+    /// Create a stack-allocated [`GridBuf`], using a [`Vec`] for storage, and
+    /// clone this entire grid into it.
+    fn to_vec_grid(&self) -> VecGrid<Self::Item>
+    where
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        let mut vec = Vec::with_capacity(self.area());
+        for row in self.rows() {
+            if let Some(row) = row.as_slice() {
+                vec.extend_from_slice(row);
+            } else {
+                vec.extend(row.iter().cloned())
+            }
+        }
+        GridBuf::with_store(self.width(), self.height(), vec)
+    }
+
+    /// Create a [`VecGrid`] the same size as this grid, where each value is the result
+    /// of calling `f` on the corresponding value in this grid. Values are visited in
+    /// row-major order.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridBuf};
+    /// let heights = GridBuf::with_store(3, 2, [
+    ///     0, 3, 1,
+    ///     4, 0, 2,
+    /// ]);
+    ///
+    /// let water = heights.map(|&h| h < 2);
+    /// assert_eq!(water.as_slice(), &[
+    ///     true, false, true,
+    ///     false, true, false,
+    /// ]);
+    ///
+    /// let doubled = heights.view(1, 0, 2, 2).map(|&h| h * 2);
+    /// assert_eq!(doubled.size(), (2, 2));
+    /// assert_eq!(doubled.as_slice(), &[
+    ///     6, 2,
+    ///     0, 4,
+    /// ]);
+    /// ```
+    fn map<U, F>(&self, mut f: F) -> VecGrid<U>
+    where
+        F: FnMut(&Self::Item) -> U,
+        Self: Sized,
+    {
+        if let Some(slice) = self.contiguous_slice() {
+            return GridBuf::with_store(self.width(), self.height(), slice.iter().map(f).collect());
+        }
+        let mut vec = Vec::with_capacity(self.area());
+        for row in self.rows() {
+            vec.extend(row.iter().map(&mut f));
+        }
+        GridBuf::with_store(self.width(), self.height(), vec)
+    }
+
+    /// Like [`map`](Self::map), but `f` is also given the `(x, y)` position of each value.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let gradient = VecGrid::<()>::new(3, 2).map_indexed(|x, y, _| x + y * 10);
+    /// assert_eq!(gradient.as_slice(), &[
+    ///     0, 1, 2,
+    ///     10, 11, 12,
+    /// ]);
+    /// ```
+    fn map_indexed<U, F>(&self, mut f: F) -> VecGrid<U>
+    where
+        F: FnMut(usize, usize, &Self::Item) -> U,
+        Self: Sized,
+    {
+        let mut vec = Vec::with_capacity(self.area());
+        for row in self.rows() {
+            let y = row.index();
+            vec.extend(row.iter().enumerate().map(|(x, val)| f(x, y, val)));
+        }
+        GridBuf::with_store(self.width(), self.height(), vec)
+    }
+
+    /// Like [`map`](Self::map), but writes the results into an existing grid instead of
+    /// allocating a new one. Panics if the grids are not the same size.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    ///
+    /// let mut odd = VecGrid::new(3, 2);
+    /// nums.map_into(&mut odd, |n| n % 2 == 1);
+    /// assert_eq!(odd.as_slice(), &[
+    ///     true, false, true,
+    ///     false, true, false,
+    /// ]);
+    /// ```
+    fn map_into<D, F>(&self, dst: &mut D, mut f: F)
+    where
+        D: GridMut,
+        F: FnMut(&Self::Item) -> D::Item,
+        Self: Sized,
+    {
+        assert!(self.same_size(dst), "grids are not the same size");
+        for (mut dst, src) in dst.rows_mut().zip(self.rows()) {
+            if let (Some(dst), Some(src)) = (dst.as_mut_slice(), src.as_slice()) {
+                for (dst, src) in dst.iter_mut().zip(src) {
+                    *dst = f(src);
+                }
+            } else {
+                for (dst, src) in dst.iter_mut().zip(src.iter()) {
+                    *dst = f(src);
+                }
+            }
+        }
+    }
+
+    /// Convolve the grid with `kernel`, returning a [`VecGrid`] of the results. The kernel
+    /// is centered on each cell (at `(kernel.width() / 2, kernel.height() / 2)`), and each
+    /// output value is the sum of the kernel's values multiplied by the values beneath them.
+    /// Samples that fall outside of the grid are handled according to `edge`. Panics if the
+    /// kernel's width or height is even.
+    ///
+    /// ```
+    /// # use grid::{EdgeMode, Grid};
+    /// let nums = [
+    ///     [0.0, 0.0, 0.0],
+    ///     [0.0, 9.0, 0.0],
+    ///     [0.0, 0.0, 3.0],
+    /// ];
+    /// let blur = [[1.0 / 9.0; 3]; 3];
+    ///
+    /// let out = nums.convolve(&blur, EdgeMode::Zero);
+    /// assert_eq!(out.as_slice(), &[
+    ///     1.0, 1.0, 1.0,
+    ///     1.0, 4.0 / 3.0, 4.0 / 3.0,
+    ///     1.0, 4.0 / 3.0, 4.0 / 3.0,
+    /// ]);
+    ///
+    /// let wrap = nums.convolve(&blur, EdgeMode::Wrap);
+    /// assert!((wrap[(0, 0)] - 12.0 / 9.0).abs() < 1e-6);
+    ///
+    /// let clamp = nums.convolve(&blur, EdgeMode::Clamp);
+    /// assert!((clamp[(2, 2)] - 21.0 / 9.0).abs() < 1e-6);
+    /// ```
+    fn convolve<K>(&self, kernel: &K, edge: EdgeMode) -> VecGrid<f32>
+    where
+        K: Grid<Item = f32>,
+        Self::Item: Into<f32> + Copy,
+        Self: Sized,
+    {
+        self.map(|&val| val.into())
+            .convolve_with(kernel, edge, |sum, k, val| sum + k * val)
+    }
+
+    /// Convolve the grid with `kernel`, using `combine` to fold each kernel value and the
+    /// value beneath it into an accumulator that starts out as `Default::default()`. The
+    /// kernel is centered on each cell like in [`convolve`](Self::convolve), and samples
+    /// that fall outside of the grid are handled according to `edge`. Panics if the
+    /// kernel's width or height is even.
+    ///
+    /// ```
+    /// # use grid::{EdgeMode, Grid, VecGrid};
+    /// let nums = VecGrid::with_store(3, 3, vec![3.0, 3.0, 3.0, 3.0, 12.0, 3.0, 3.0, 3.0, 3.0]);
+    /// let mean = [[1.0f32 / 9.0; 3]; 3];
+    ///
+    /// let blur = nums.convolve_with(&mean, EdgeMode::Clamp, |sum, k, val| sum + k * val);
+    /// assert!((blur[(1, 1)] - 4.0).abs() < 1e-6);
+    ///
+    /// let sobel_x = [[-1, 0, 1], [-2, 0, 2], [-1, 0, 1]];
+    /// let ramp = VecGrid::from_fn(4, 3, |x, _| x as i32);
+    /// let edges = ramp.convolve_with(&sobel_x, EdgeMode::Clamp, |sum, k, val| sum + k * val);
+    /// assert_eq!(edges.as_slice(), &[4, 8, 8, 4, 4, 8, 8, 4, 4, 8, 8, 4]);
+    ///
+    /// // Count how many samples are combined into each cell.
+    /// let ones = [[1; 3]; 3];
+    /// let zero = ramp.convolve_with(&ones, EdgeMode::Zero, |n, _, _| n + 1);
+    /// let skip = ramp.convolve_with(&ones, EdgeMode::Skip, |n, _, _| n + 1);
+    /// assert_eq!(zero[(0, 0)], 9);
+    /// assert_eq!(skip[(0, 0)], 4);
+    /// ```
+    fn convolve_with<K, F>(&self, kernel: &K, edge: EdgeMode, mut combine: F) -> VecGrid<Self::Item>
+    where
+        K: Grid,
+        F: FnMut(Self::Item, &K::Item, &Self::Item) -> Self::Item,
+        Self::Item: Default,
+        Self: Sized,
+    {
+        assert!(
+            kernel.width() % 2 == 1 && kernel.height() % 2 == 1,
+            "kernel must be odd-sized"
+        );
+        let cx = (kernel.width() / 2) as isize;
+        let cy = (kernel.height() / 2) as isize;
+        let zero = Self::Item::default();
+        VecGrid::from_fn(self.width(), self.height(), |x, y| {
+            let mut acc = Self::Item::default();
+            for (k, kx, ky) in kernel.iter() {
+                let sx = x as isize + kx as isize - cx;
+                let sy = y as isize + ky as isize - cy;
+                let val = match edge {
+                    EdgeMode::Clamp => self.get_at(Clamp((sx, sy))),
+                    EdgeMode::Wrap => self.get_at(Wrap((sx, sy))),
+                    EdgeMode::Zero => self.get_at((sx, sy)).or(Some(&zero)),
+                    EdgeMode::Skip => self.get_at((sx, sy)),
+                };
+                if let Some(val) = val {
+                    acc = combine(acc, k, val);
+                }
+            }
+            acc
+        })
+    }
+
+    /// Create a [`VecGrid`] that is a transposed copy of this grid, so it is `height × width`
+    /// and `out.get(y, x) == self.get(x, y)`. To transpose without copying, use
+    /// [`transpose_view`](Self::transpose_view).
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// let trans = nums.transpose();
+    /// assert_eq!(trans.size(), (2, 3));
+    /// assert_eq!(trans.as_slice(), &[
+    ///     0, 3,
+    ///     1, 4,
+    ///     2, 5,
+    /// ]);
+    /// ```
+    fn transpose(&self) -> VecGrid<Self::Item>
+    where
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        let mut vec = Vec::with_capacity(self.area());
+        for col in self.cols() {
+            vec.extend(col.iter().cloned());
+        }
+        GridBuf::with_store(self.height(), self.width(), vec)
+    }
+
+    /// Clone the values of this grid into `dst` transposed, so that
+    /// `dst.get(y, x) == self.get(x, y)`. The grid is processed in small tiles to stay
+    /// cache-friendly on large grids. Panics if `dst` is not `height × width`.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let src = VecGrid::from_fn(70, 45, |x, y| x * 100 + y);
+    /// let mut dst = VecGrid::new(45, 70);
+    /// src.transpose_into(&mut dst);
+    /// assert_eq!(dst, src.transpose());
+    /// ```
+    fn transpose_into<D>(&self, dst: &mut D)
+    where
+        D: GridMut<Item = Self::Item>,
+        Self::Item: Clone,
+    {
+        const TILE: usize = 32;
+        let (w, h) = (self.width(), self.height());
+        assert!(
+            dst.width() == h && dst.height() == w,
+            "destination grid is not the transposed size"
+        );
+        for ty in (0..h).step_by(TILE) {
+            for tx in (0..w).step_by(TILE) {
+                for y in ty..(ty + TILE).min(h) {
+                    for x in tx..(tx + TILE).min(w) {
+                        // SAFETY: `x < w` and `y < h`, and `dst` was checked to be `h × w`.
+                        unsafe {
+                            *dst.get_unchecked_mut(y, x) = self.get_unchecked(x, y).clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Scale this grid into `dst` using nearest-neighbor sampling, so each destination cell
+    /// `(x, y)` gets a clone of the source cell `(x * width / dst.width(), y * height /
+    /// dst.height())`. This works for both upscaling and downscaling. If this grid is
+    /// empty, `dst` is left unchanged.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let src = [
+    ///     [1, 2],
+    ///     [3, 4],
+    /// ];
+    ///
+    /// let mut big = VecGrid::new(4, 4);
+    /// src.resize_nearest(&mut big);
+    /// assert_eq!(big.as_slice(), &[
+    ///     1, 1, 2, 2,
+    ///     1, 1, 2, 2,
+    ///     3, 3, 4, 4,
+    ///     3, 3, 4, 4,
+    /// ]);
+    ///
+    /// let mut small = VecGrid::new(1, 2);
+    /// big.resize_nearest(&mut small);
+    /// assert_eq!(small.as_slice(), &[1, 3]);
+    /// ```
+    fn resize_nearest<D>(&self, dst: &mut D)
+    where
+        D: GridMut<Item = Self::Item>,
+        Self::Item: Clone,
+    {
+        let (sw, sh) = (self.width(), self.height());
+        let (dw, dh) = (dst.width(), dst.height());
+        if sw == 0 || sh == 0 {
+            return;
+        }
+        for y in 0..dh {
+            let sy = y * sh / dh;
+            for x in 0..dw {
+                let sx = x * sw / dw;
+                // SAFETY: `sx < sw` and `sy < sh` because `x < dw` and `y < dh`, and `x`
+                // and `y` are within `dst`'s bounds.
+                unsafe {
+                    *dst.get_unchecked_mut(x, y) = self.get_unchecked(sx, sy).clone();
+                }
+            }
+        }
+    }
+
+    /// Compute the next state of a cellular automaton into `dst`. For every cell, `rule` is
+    /// called with the cell's current value and its 8 [`neighbors`](Self::neighbors) (with
+    /// `None` for those out of bounds), and returns the cell's next value. Panics if `dst`
+    /// is not the same size as this grid.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let heat = [
+    ///     [0, 0, 0],
+    ///     [0, 9, 0],
+    /// ];
+    ///
+    /// // each cell becomes the hottest of itself and its neighbors
+    /// let mut next = [[0; 3]; 2];
+    /// heat.step_into(&mut next, |&n, neighbors| {
+    ///     neighbors.iter().flatten().fold(n, |max, &&n| max.max(n))
+    /// });
+    /// assert_eq!(next, [[9; 3]; 2]);
+    /// ```
+    fn step_into<D, F>(&self, dst: &mut D, mut rule: F)
+    where
+        D: GridMut<Item = Self::Item>,
+        F: FnMut(&Self::Item, &[Option<&Self::Item>; 8]) -> D::Item,
+    {
+        assert!(self.same_size(dst), "grids are not the same size");
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                // SAFETY: `x` and `y` are within this grid's bounds, and `dst` is the same
+                // size as this grid.
+                unsafe {
+                    let next = rule(self.get_unchecked(x, y), &self.neighbors(x, y));
+                    *dst.get_unchecked_mut(x, y) = next;
+                }
+            }
+        }
+    }
+
+    /// Compute the next generation of Conway's Game of Life into `dst`, using the classic
+    /// B3/S23 rule: dead cells with exactly 3 live neighbors are born, and live cells with
+    /// 2 or 3 live neighbors survive. Cells outside of the grid count as dead. Panics if
+    /// `dst` is not the same size as this grid.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let blinker = [
+    ///     [false, false, false],
+    ///     [true, true, true],
+    ///     [false, false, false],
+    /// ];
+    ///
+    /// let mut next = [[false; 3]; 3];
+    /// blinker.life_step(&mut next);
+    /// assert_eq!(next, [
+    ///     [false, true, false],
+    ///     [false, true, false],
+    ///     [false, true, false],
+    /// ]);
+    ///
+    /// let mut after = [[false; 3]; 3];
+    /// next.life_step(&mut after);
+    /// assert_eq!(after, blinker);
+    /// ```
+    fn life_step<D>(&self, dst: &mut D)
+    where
+        D: GridMut<Item = bool>,
+        Self: Grid<Item = bool>,
+    {
+        self.step_into(dst, |&alive, neighbors| {
+            let live = neighbors.iter().filter(|&&n| n == Some(&true)).count();
+            live == 3 || (alive && live == 2)
+        });
+    }
+
+    /// Create a [`VecGrid`] that is a copy of this grid rotated 180°, so that
+    /// `out.get(x, y) == self.get(width - 1 - x, height - 1 - y)`.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2],
+    ///     [3, 4, 5],
+    /// ];
+    ///
+    /// let rot = nums.rotate_180();
+    /// assert_eq!(rot.size(), (3, 2));
+    /// assert_eq!(rot.as_slice(), &[
+    ///     5, 4, 3,
+    ///     2, 1, 0,
+    /// ]);
+    /// ```
+    fn rotate_180(&self) -> VecGrid<Self::Item>
+    where
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        let mut vec = Vec::with_capacity(self.area());
+        for row in self.rows().rev() {
+            vec.extend(row.iter().rev().cloned());
+        }
+        GridBuf::with_store(self.width(), self.height(), vec)
+    }
+
+    /// Iterate over all values in the grid, with their positions. The iterator can also be
+    /// reversed to walk the grid from the bottom-right.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2],
+    ///     [3, 4],
+    /// ];
+    ///
+    /// let rev: Vec<_> = nums.iter().rev().map(|(n, _, _)| *n).collect();
+    /// assert_eq!(rev, vec![4, 3, 2, 1]);
+    ///
+    /// let mut iter = nums.iter();
+    /// assert_eq!(iter.next(), Some((&1, 0, 0)));
+    /// assert_eq!(iter.next_back(), Some((&4, 1, 1)));
+    /// assert_eq!(iter.len(), 2);
+    /// assert_eq!(iter.next_back(), Some((&3, 0, 1)));
+    /// assert_eq!(iter.next(), Some((&2, 1, 0)));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next_back(), None);
+    /// ```
+    #[inline]
+    fn iter(&self) -> GridIter<&Self>
+    where
+        Self: Sized,
+    {
+        GridIter::new(self, self.width() * self.height())
+    }
+
+    /// Iterate over all values in the grid in parallel, with their positions. Values are
+    /// indexed in row-major order, so [`enumerate`](rayon::iter::IndexedParallelIterator::enumerate)
+    /// gives `y * width + x` for each value.
+    ///
+    /// This requires the grid and its values to be [`Sync`], because they are shared between
+    /// rayon's worker threads.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// use rayon::prelude::*;
+    ///
+    /// let grid = VecGrid::with_store(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let scores: Vec<_> = grid.par_iter().map(|(n, x, y)| n * 10 + x + y).collect();
+    /// assert_eq!(scores, vec![10, 21, 32, 41, 52, 63]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_iter(&self) -> crate::ParGridIter<'_, Self>
+    where
+        Self: Sized + Sync,
+        Self::Item: Sync,
+    {
+        crate::ParGridIter::new(self)
+    }
+
+    /// Iterate over the values of this grid and another of the same size in pairs, with
+    /// their positions. Panics if the grids are not the same size.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let a = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    /// let b = VecGrid::with_store(2, 2, vec![1, 0, 3, 0]);
+    /// let diff: Vec<_> = a.zip(&b).filter(|(a, b, _, _)| a != b).map(|(_, _, x, y)| (x, y)).collect();
+    /// assert_eq!(diff, vec![(1, 0), (1, 1)]);
+    /// ```
+    #[inline]
+    fn zip<'a, G2: Grid>(&'a self, other: &'a G2) -> ZipIter<&'a Self, &'a G2>
+    where
+        Self: Sized,
+    {
+        assert!(self.same_size(other), "grids are not the same size");
+        ZipIter::new(self, other)
+    }
+
+    /// Iterate over the values along a diagonal of the grid, from top-left to bottom-right,
+    /// with their positions. The diagonal contains every cell where `x - y == offset`, so
+    /// `0` is the main diagonal starting at the origin, positive offsets are above it, and
+    /// negative offsets are below it. On non-square grids the diagonal stops at whichever
+    /// edge it reaches first.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    ///
+    /// let diag: Vec<_> = nums.diagonal(0).collect();
+    /// assert_eq!(diag, vec![(&1, 0, 0), (&5, 1, 1)]);
+    /// assert_eq!(nums.diagonal(1).map(|(n, _, _)| *n).collect::<Vec<_>>(), vec![2, 6]);
+    /// assert_eq!(nums.diagonal(-1).map(|(n, _, _)| *n).collect::<Vec<_>>(), vec![4]);
+    /// assert_eq!(nums.diagonal(3).count(), 0);
+    /// ```
+    #[inline]
+    fn diagonal(&self, offset: isize) -> DiagonalIter<&Self>
+    where
+        Self: Sized,
+    {
+        DiagonalIter::main(self, offset)
+    }
+
+    /// Iterate over the values along an anti-diagonal of the grid, from top-right to
+    /// bottom-left, with their positions. The anti-diagonal contains every cell where
+    /// `x + y == width - 1 + offset`, so `0` starts at the top-right corner, positive
+    /// offsets are below it, and negative offsets are above it.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let square = [
+    ///     [2, 7, 6],
+    ///     [9, 5, 1],
+    ///     [4, 3, 8],
+    /// ];
+    ///
+    /// let sum = |iter: grid::DiagonalIter<_>| iter.map(|(n, _, _)| n).sum::<i32>();
+    /// assert_eq!(sum(square.diagonal(0)), 15);
+    /// assert_eq!(sum(square.anti_diagonal(0)), 15);
+    ///
+    /// let anti: Vec<_> = square.anti_diagonal(-1).collect();
+    /// assert_eq!(anti, vec![(&7, 1, 0), (&9, 0, 1)]);
+    /// assert_eq!(sum(square.anti_diagonal(2)), 8);
+    /// ```
+    #[inline]
+    fn anti_diagonal(&self, offset: isize) -> DiagonalIter<&Self>
+    where
+        Self: Sized,
+    {
+        DiagonalIter::anti(self, offset)
+    }
+
+    /// Iterate over all columns in the grid.
+    #[inline]
+    fn cols(&self) -> ColsIter<&Self>
+    where
+        Self: Sized,
+    {
+        ColsIter::new(self, self.width())
+    }
+
+    /// Return the column `x`, or `None` if `x` is out of bounds.
+    #[inline]
+    fn try_col(&self, x: usize) -> Option<Col<&Self>> {
+        (x < self.width()).then(|| Col::new(self, x))
+    }
+
+    /// Return the column `x`. Panics if `x` is out of bounds.
+    #[inline]
+    fn col(&self, x: usize) -> Col<&Self> {
+        self.try_col(x).expect("column index out of bounds")
+    }
+
+    /// Iterate over the rows of the grid.
+    #[inline]
+    fn rows(&self) -> RowsIter<&Self>
+    where
+        Self: Sized,
+    {
+        RowsIter::new(self, self.height())
+    }
+
+    /// Return the row `y`, or `None` if `y` is out of bounds.
+    #[inline]
+    fn try_row(&self, y: usize) -> Option<Row<&Self>> {
+        (y < self.height()).then(|| Row::new(self, y))
+    }
+
+    /// Return the row `y`. Panics if `y` is out of bounds.
+    #[inline]
+    fn row(&self, y: usize) -> Row<&Self> {
+        self.try_row(y).expect("row index out of bounds")
+    }
+
+    /// Search the grid in row-major order for the first value that satisfies `pred`, and
+    /// return its `(x, y)` position, or `None` if no value matches.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let map = [
+    ///     ['.', '.', '.'],
+    ///     ['.', '@', '.'],
+    ///     ['.', '.', '@'],
+    /// ];
+    ///
+    /// assert_eq!(map.position(|&c| c == '@'), Some((1, 1)));
+    /// assert_eq!(map.view(2, 0, 1, 3).position(|&c| c == '@'), Some((0, 2)));
+    /// assert_eq!(map.position(|&c| c == '#'), None);
+    /// ```
+    fn position<P>(&self, mut pred: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        for row in self.rows() {
+            let x = if let Some(slice) = row.as_slice() {
+                slice.iter().position(&mut pred)
+            } else {
+                row.iter().position(&mut pred)
+            };
+            if let Some(x) = x {
+                return Some((x, row.index()));
+            }
+        }
+        None
+    }
+
+    /// Search the grid in row-major order for the first value that satisfies `pred`, and
+    /// return it along with its position, or `None` if no value matches.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 3, 5],
+    ///     [7, 8, 9],
+    /// ];
+    ///
+    /// assert_eq!(nums.find(|&n| n % 2 == 0), Some((&8, 1, 1)));
+    /// assert_eq!(nums.find(|&n| n > 9), None);
+    /// ```
+    fn find<P>(&self, pred: P) -> Option<(&Self::Item, usize, usize)>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        let (x, y) = self.position(pred)?;
+        Some((self.get(x, y)?, x, y))
+    }
+
+    /// Count how many values in the grid satisfy `pred`.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let nums = [
+    ///     [0, 1, 0],
+    ///     [2, 0, 3],
+    /// ];
+    ///
+    /// assert_eq!(nums.count(|&n| n != 0), 3);
+    /// assert_eq!(VecGrid::<i32>::new(0, 0).count(|&n| n != 0), 0);
+    /// ```
+    fn count<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        let mut count = 0;
+        for row in self.rows() {
+            count += if let Some(slice) = row.as_slice() {
+                slice.iter().filter(|val| pred(val)).count()
+            } else {
+                row.iter().filter(|val| pred(val)).count()
+            };
+        }
+        count
+    }
+
+    /// Returns `true` if the grid contains a value equal to `value`.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [1, 2],
+    ///     [3, 4],
     /// ];
     ///
-    /// assert_eq!(nums.row_slice(0), Some([0, 1, 2].as_slice()));
-    /// assert_eq!(nums.row_slice(1), Some([3, 4, 5].as_slice()));
-    /// assert_eq!(nums.row_slice(2), Some([6, 7, 8].as_slice()));
-    /// assert_eq!(nums.row_slice(3), None);
+    /// assert!(nums.contains(&3));
+    /// assert!(!nums.contains(&5));
     /// ```
-    fn row_slice(&self, y: usize) -> Option<&[Self::Item]>;
-
-    /// Returns row `y` of the grid as a slice if it is able to do so. This variation
-    /// can take signed integers, or y-values in a [`Wrap`](crate::Wrap) or
-    /// [`Clamp`](crate::Clamp).
     #[inline]
-    fn row_slice_at(&self, y: impl CoordComponent) -> Option<&[Self::Item]> {
-        self.row_slice(y.to_grid(self.height())?)
+    fn contains(&self, value: &Self::Item) -> bool
+    where
+        Self::Item: PartialEq,
+        Self: Sized,
+    {
+        self.position(|val| val == value).is_some()
     }
 
-    /// Returns true if both grids are the same size.
+    /// Find the smallest region that contains every value satisfying `pred`, returned as
+    /// `(x, y, w, h)`, or `None` if no value matches.
     ///
     /// ```
     /// # use grid::Grid;
-    /// let nums = [
-    ///     [1, 2],
-    ///     [3, 4],
+    /// let sprite = [
+    ///     [0, 0, 0, 0],
+    ///     [0, 1, 0, 0],
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 0, 1],
     /// ];
     ///
-    /// assert!(nums.same_size(&[
-    ///     [0, 0],
-    ///     [0, 0],
-    /// ]));
-    /// assert!(!nums.same_size(&[
-    ///     [0, 0, 0],
-    ///     [0, 0, 0],
-    /// ]));
-    /// assert!(!nums.same_size(&[
-    ///     [0, 0],
-    ///     [0, 0],
-    ///     [0, 0],
-    /// ]));
+    /// assert_eq!(sprite.bounds_of(|&p| p != 0), Some((1, 1, 3, 3)));
+    /// assert_eq!(sprite.bounds_of(|&p| p == 0), Some((0, 0, 4, 4)));
+    /// assert_eq!(sprite.bounds_of(|&p| p == 2), None);
     /// ```
-    #[inline]
-    fn same_size<G2: Grid>(&self, other: &G2) -> bool {
-        self.width() == other.width() && self.height() == other.height()
+    fn bounds_of<P>(&self, mut pred: P) -> Option<(usize, usize, usize, usize)>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+        for (val, x, y) in self.iter() {
+            if pred(val) {
+                bounds = Some(match bounds {
+                    Some((x0, y0, x1, y1)) => (x0.min(x), y0, x1.max(x), y1.max(y)),
+                    None => (x, y, x, y),
+                });
+            }
+        }
+        bounds.map(|(x0, y0, x1, y1)| (x0, y0, x1 - x0 + 1, y1 - y0 + 1))
     }
 
-    /// Get an immutable [`View`] into this grid, or `None` if the provided region is
-    /// out of bounds.
+    /// Get the smallest [`View`] that contains every value satisfying `keep`, or `None`
+    /// if no value matches. See [`bounds_of`](Self::bounds_of).
     ///
     /// ```
     /// # use grid::Grid;
-    /// let nums = [
-    ///     [0, 1, 2],
-    ///     [3, 4, 5],
-    ///     [6, 7, 8],
+    /// let sprite = [
+    ///     [0, 0, 0, 0],
+    ///     [0, 1, 2, 0],
+    ///     [0, 3, 0, 0],
+    ///     [0, 0, 0, 0],
     /// ];
     ///
-    /// assert_eq!(nums.get(0, 0), Some(&0));
-    /// assert_eq!(nums.get(1, 1), Some(&4));
-    ///
-    /// let view = nums.try_view(1, 1, 2, 2).unwrap();
-    /// assert_eq!(view.get(0, 0), Some(&4));
-    /// assert_eq!(view.get(1, 1), Some(&8));
+    /// let trimmed = sprite.trim(|&p| p != 0).unwrap();
+    /// assert_eq!(trimmed, [[1, 2], [3, 0]].view(0, 0, 2, 2));
     ///
-    /// assert!(nums.try_view(2, 2, 5, 5).is_none());
+    /// let right = sprite.view(2, 0, 2, 4);
+    /// let corner = right.trim(|&p| p != 0).unwrap();
+    /// assert_eq!((corner.root_x(), corner.root_y(), corner.size()), (2, 1, (1, 1)));
+    /// assert!(sprite.trim(|&p| p > 3).is_none());
     /// ```
     #[inline]
-    fn try_view(&self, x: usize, y: usize, w: usize, h: usize) -> Option<View<&Self::Root>> {
-        if x.checked_add(w)? <= self.width() && y.checked_add(h)? <= self.height() {
-            let x = self.root_x() + x;
-            let y = self.root_y() + y;
-            Some(View::new(self.root(), x, y, w, h))
-        } else {
-            None
-        }
-    }
-
-    /// Get an immutable [`View`] into this grid. Panicks if the provided region is out
-    /// of bounds.
-    #[inline]
-    fn view(&self, x: usize, y: usize, w: usize, h: usize) -> View<&Self::Root> {
-        self.try_view(x, y, w, h)
-            .expect("view does not overlap grid's bounds")
+    fn trim<P>(&self, keep: P) -> Option<View<&Self::Root>>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        let (x, y, w, h) = self.bounds_of(keep)?;
+        Some(self.view(x, y, w, h))
     }
 
-    /// Create a [`GridBuf`] using the provided storage and clone this entire
-    /// grid into it. The resulting grid will be the same size as this one.
+    /// Returns `true` if this grid and `other` have the same size and values, regardless
+    /// of how either of them is stored.
+    ///
+    /// ```
+    /// # use grid::{ArrGrid, Grid, VecGrid};
+    /// let arr = [[1, 2], [3, 4]];
+    /// let vec = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    /// let buf = ArrGrid::with_store(4, 1, [1, 2, 3, 4]);
+    ///
+    /// assert!(arr.content_eq(&vec));
+    /// assert!(vec.content_eq(&arr.view(0, 0, 2, 2)));
+    /// assert!(!vec.content_eq(&buf));
+    /// ```
     #[inline]
-    fn to_grid_buf<S>(&self, store: S) -> GridBuf<Self::Item, S>
+    fn content_eq<G2: Grid>(&self, other: &G2) -> bool
     where
-        S: AsRef<[Self::Item]> + AsMut<[Self::Item]>,
-        Self::Item: Clone,
+        Self::Item: PartialEq<G2::Item>,
         Self: Sized,
     {
-        let mut buf = GridBuf::with_store(self.width(), self.height(), store);
-        buf.draw_cloned(self);
-        buf
+        self.eq_grid(other)
     }
 
-    /// Create a stack-allocated [`GridBuf`], using an `N`-sized array for storage,
-    /// and clone this entire grid into it. Panics if `N` is not exactly the area
-    /// of the grid (`width * height`).
+    /// Feed the grid's size, followed by its values in row-major order, into `state`.
+    /// Grids that are [`content_eq`](Self::content_eq) produce the same hash regardless of
+    /// how they are stored.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hasher};
+    ///
+    /// fn hash(grid: &impl Grid<Item = i32>) -> u64 {
+    ///     let mut state = DefaultHasher::new();
+    ///     grid.content_hash(&mut state);
+    ///     state.finish()
+    /// }
+    ///
+    /// let arr = [[1, 2], [3, 4]];
+    /// let vec = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    /// let wide = VecGrid::with_store(4, 1, vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(hash(&arr), hash(&vec));
+    /// assert_ne!(hash(&vec), hash(&wide));
+    ///
+    /// // `GridBuf`'s `Hash` impl uses the same scheme.
+    /// let state = BuildHasherDefault::<DefaultHasher>::default();
+    /// assert_eq!(state.hash_one(&vec), hash(&arr));
+    /// ```
     #[inline]
-    fn to_arr_grid<const N: usize>(&self) -> ArrGrid<Self::Item, N>
+    fn content_hash<H: Hasher>(&self, state: &mut H)
     where
-        Self::Item: Default + Clone,
-        Self: Sized,
+        Self::Item: Hash,
     {
-        assert_eq!(self.width() * self.height(), N);
-        let mut arr = std::array::from_fn(|_| Self::Item::default());
-        for (dst, src) in arr.chunks_exact_mut(self.width()).zip(self.rows()) {
-            if let Some(src) = src.as_slice() {
-                dst.clone_from_slice(src);
-            } else {
-                for (dst, src) in dst.iter_mut().zip(&src) {
-                    *dst = src.clone();
-                }
+        self.width().hash(state);
+        self.height().hash(state);
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                // SAFETY: `x` and `y` are always within the grid's bounds.
+                unsafe { self.get_unchecked(x, y) }.hash(state);
             }
         }
-        GridBuf::with_store(self.width(), self.height(), arr)
     }
 
-    /// Create a stack-allocated [`GridBuf`], using a [`Vec`] for storage, and
-    /// clone this entire grid into it.
-    fn to_vec_grid(&self) -> VecGrid<Self::Item>
+    /// Fold every value in the grid into an accumulator, in row-major order.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let pixels = [
+    ///     [12, 80, 3],
+    ///     [200, 45, 97],
+    /// ];
+    ///
+    /// let brightest = pixels.fold(0, |max, &p| max.max(p));
+    /// assert_eq!(brightest, 200);
+    /// ```
+    #[inline]
+    fn fold<B, F>(&self, init: B, mut f: F) -> B
     where
-        Self::Item: Clone,
-        Self: Sized,
+        F: FnMut(B, &Self::Item) -> B,
     {
-        let mut vec = Vec::with_capacity(self.area());
-        for row in self.rows() {
-            if let Some(row) = row.as_slice() {
-                vec.extend_from_slice(row);
+        let mut acc = init;
+        for y in 0..self.height() {
+            if let Some(row) = self.row_slice(y) {
+                acc = row.iter().fold(acc, &mut f);
             } else {
-                vec.extend(row.iter().cloned())
+                for x in 0..self.width() {
+                    // SAFETY: `x` and `y` are always within the grid's bounds.
+                    acc = f(acc, unsafe { self.get_unchecked(x, y) });
+                }
             }
         }
-        GridBuf::with_store(self.width(), self.height(), vec)
+        acc
     }
 
-    /// Iterate over all values in the grid, with their positions.
+    /// Like [`fold`](Self::fold), but `f` is also given the `(x, y)` position of each
+    /// value.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let pixels = [
+    ///     [12, 80, 3],
+    ///     [200, 45, 97],
+    /// ];
+    ///
+    /// let brightest = pixels.fold_indexed((0, 0, 0), |max, x, y, &p| {
+    ///     if p > max.0 { (p, x, y) } else { max }
+    /// });
+    /// assert_eq!(brightest, (200, 0, 1));
+    /// ```
     #[inline]
-    fn iter(&self) -> GridIter<&Self>
+    fn fold_indexed<B, F>(&self, init: B, mut f: F) -> B
     where
-        Self: Sized,
+        F: FnMut(B, usize, usize, &Self::Item) -> B,
     {
-        GridIter::new(self)
+        let mut acc = init;
+        for y in 0..self.height() {
+            if let Some(row) = self.row_slice(y) {
+                for (x, val) in row.iter().enumerate() {
+                    acc = f(acc, x, y, val);
+                }
+            } else {
+                for x in 0..self.width() {
+                    // SAFETY: `x` and `y` are always within the grid's bounds.
+                    acc = f(acc, x, y, unsafe { self.get_unchecked(x, y) });
+                }
+            }
+        }
+        acc
     }
 
-    /// Iterate over all columns in the grid.
-    #[inline]
-    fn cols(&self) -> ColsIter<&Self>
+    /// Count how many times each distinct value appears in the grid.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let map = [
+    ///     ['#', '.', '#'],
+    ///     ['.', '.', '~'],
+    /// ];
+    ///
+    /// let counts = map.histogram();
+    /// assert_eq!(counts[&'.'], 3);
+    /// assert_eq!(counts[&'#'], 2);
+    /// assert_eq!(counts[&'~'], 1);
+    /// assert_eq!(counts.get(&'@'), None);
+    /// ```
+    fn histogram(&self) -> HashMap<Self::Item, usize>
     where
-        Self: Sized,
+        Self::Item: Eq + Hash + Clone,
     {
-        ColsIter::new(self, self.width())
-    }
-
-    /// Return the column `x`, or `None` if `x` is out of bounds.
-    #[inline]
-    fn try_col(&self, x: usize) -> Option<Col<&Self>> {
-        (x < self.width()).then(|| Col::new(self, x))
-    }
-
-    /// Return the column `x`. Panics if `x` is out of bounds.
-    #[inline]
-    fn col(&self, x: usize) -> Col<&Self> {
-        self.try_col(x).expect("column index out of bounds")
+        self.counts_by(Self::Item::clone)
     }
 
-    /// Iterate over the rows of the grid.
-    #[inline]
-    fn rows(&self) -> RowsIter<&Self>
+    /// Count how many values in the grid share each key produced by `key`.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let heights = [
+    ///     [0.1, 0.7, 0.2],
+    ///     [0.9, 0.4, 0.8],
+    /// ];
+    ///
+    /// let counts = heights.counts_by(|&h| h >= 0.5);
+    /// assert_eq!(counts[&true], 3);
+    /// assert_eq!(counts[&false], 3);
+    /// ```
+    fn counts_by<K, F>(&self, mut key: F) -> HashMap<K, usize>
     where
-        Self: Sized,
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
     {
-        RowsIter::new(self, self.height())
-    }
-
-    /// Return the row `y`, or `None` if `y` is out of bounds.
-    #[inline]
-    fn try_row(&self, y: usize) -> Option<Row<&Self>> {
-        (y < self.height()).then(|| Row::new(self, y))
-    }
-
-    /// Return the row `y`. Panics if `y` is out of bounds.
-    #[inline]
-    fn row(&self, y: usize) -> Row<&Self> {
-        self.try_row(y).expect("row index out of bounds")
+        let mut counts = HashMap::new();
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                // SAFETY: `x` and `y` are always within the grid's bounds.
+                let val = unsafe { self.get_unchecked(x, y) };
+                *counts.entry(key(val)).or_insert(0) += 1;
+            }
+        }
+        counts
     }
 
     #[inline]
@@ -442,35 +1895,128 @@ pub trait Grid {
         writeln!(f)
     }
 
-    // IDEA: getting views from ranges could also work
-    /*fn view(
-        &self,
-        cols: impl RangeBounds<usize>,
-        rows: impl RangeBounds<usize>,
-    ) -> Option<GridView<'_, T, Self::Root>>
+    /// Render the grid as a string, converting each value to a character with `f`. Each
+    /// row is written on its own line, followed by a newline. Empty grids produce an empty
+    /// string.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let walls = [
+    ///     [true, false, true],
+    ///     [false, false, true],
+    /// ];
+    /// let s = walls.display_with(|&wall| if wall { '#' } else { '.' });
+    /// assert_eq!(s, "#.#\n..#\n");
+    /// ```
+    #[inline]
+    fn display_with<F: FnMut(&Self::Item) -> char>(&self, mut f: F) -> String {
+        let mut s = String::with_capacity((self.width() + 1) * self.height());
+        if self.width() == 0 {
+            return s;
+        }
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                s.push(f(self.get(x, y).unwrap()));
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    /// Render a grid of characters as a string, with each row on its own line followed by
+    /// a newline. See [`display_with`](Self::display_with).
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// assert_eq!([['a', 'b'], ['c', 'd']].to_char_grid(), "ab\ncd\n");
+    /// assert_eq!([[b'#'; 3]; 0].to_char_grid(), "");
+    /// assert_eq!([[b'#'; 0]; 3].to_char_grid(), "");
+    /// ```
+    #[inline]
+    fn to_char_grid(&self) -> String
     where
-        Self::Root: Grid<T>,
+        Self::Item: Into<char> + Copy,
     {
-        fn bound_to_range(bound: impl RangeBounds<usize>, max: usize) -> Option<(usize, usize)> {
-            let lo = match bound.start_bound() {
-                Bound::Included(&lo) => lo,
-                Bound::Excluded(&lo) => lo.checked_add(1)?,
-                Bound::Unbounded => 0,
-            };
-            let hi = match bound.end_bound() {
-                Bound::Included(&hi) => hi.checked_add(1)?,
-                Bound::Excluded(&hi) => hi,
-                Bound::Unbounded => max,
-            };
-            let len = hi.checked_sub(lo)?;
-            (lo + len <= max).then(|| (lo, len))
+        self.display_with(|&val| val.into())
+    }
+
+    /// Write the grid's values in rows, with no separator between values, and a newline
+    /// between rows (but not after the last one).
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let mut s = String::new();
+    /// [['#', '.'], ['.', '#']].display_fmt(&mut s).unwrap();
+    /// assert_eq!(s, "#.\n.#");
+    /// ```
+    #[inline]
+    fn display_fmt<W: Write>(&self, mut f: W) -> std::fmt::Result
+    where
+        Self::Item: Display,
+    {
+        for y in 0..self.height() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.width() {
+                write!(f, "{}", self.get(x, y).unwrap())?;
+            }
         }
+        Ok(())
+    }
+
+    /// Get an immutable [`View`] into this grid covering the provided ranges of columns
+    /// and rows, or `None` if either range is out of bounds. This works like slicing a
+    /// [`Vec`], accepting any kind of range.
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// let nums = [
+    ///     [0, 1, 2, 3],
+    ///     [4, 5, 6, 7],
+    ///     [8, 9, 10, 11],
+    /// ];
+    ///
+    /// let view = nums.view_range(1..3, ..2).unwrap();
+    /// assert_eq!((view.root_x(), view.root_y(), view.size()), (1, 0, (2, 2)));
+    /// assert_eq!(view.get(0, 1), Some(&5));
+    ///
+    /// let view = nums.view_range(2.., 1..=2).unwrap();
+    /// assert_eq!(view.size(), (2, 2));
+    /// assert_eq!(view.get(1, 1), Some(&11));
+    ///
+    /// assert_eq!(nums.view_range(.., ..).unwrap().size(), (4, 3));
+    /// assert!(nums.view_range(3..5, ..).is_none());
+    /// assert!(nums.view_range(.., ..=3).is_none());
+    /// ```
+    #[inline]
+    fn view_range(
+        &self,
+        cols: impl RangeBounds<usize>,
+        rows: impl RangeBounds<usize>,
+    ) -> Option<View<&Self::Root>> {
         let (x, w) = bound_to_range(cols, self.width())?;
         let (y, h) = bound_to_range(rows, self.height())?;
-        let x = self.root_x() + x;
-        let y = self.root_y() + y;
-        Some(GridView::new(self.root(), x, y, w, h))
-    }*/
+        self.try_view(x, y, w, h)
+    }
+}
+
+/// Convert a range into the start and length of a span within `0..max`, or `None` if the
+/// range is out of bounds.
+#[inline]
+pub(crate) fn bound_to_range(bound: impl RangeBounds<usize>, max: usize) -> Option<(usize, usize)> {
+    let lo = match bound.start_bound() {
+        Bound::Included(&lo) => lo,
+        Bound::Excluded(&lo) => lo.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let hi = match bound.end_bound() {
+        Bound::Included(&hi) => hi.checked_add(1)?,
+        Bound::Excluded(&hi) => hi,
+        Bound::Unbounded => max,
+    };
+    let len = hi.checked_sub(lo)?;
+    (hi <= max).then_some((lo, len))
 }
 
 impl<T, const W: usize, const H: usize> Grid for [[T; W]; H] {
@@ -516,4 +2062,9 @@ impl<T, const W: usize, const H: usize> Grid for [[T; W]; H] {
     fn row_slice(&self, y: usize) -> Option<&[Self::Item]> {
         (y < H).then(|| self[y].as_slice())
     }
+
+    #[inline]
+    fn contiguous_slice(&self) -> Option<&[Self::Item]> {
+        Some(self.as_flattened())
+    }
 }
@@ -1,5 +1,7 @@
 use crate::{Coord, CoordComponent, Grid, GridIter, GridMut};
-use std::fmt::{Debug, Formatter};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
 
@@ -22,7 +24,8 @@ pub type SliceGrid<'a, T> = GridBuf<T, &'a [T]>;
 
 impl<T, S> GridBuf<T, S> {
     /// Create a new grid using the provided storage. Panics if the length
-    /// of `store` is not equal to `width * height`.
+    /// of `store` is not equal to `width * height`; see
+    /// [`try_with_store`](Self::try_with_store) for a checked version.
     #[inline]
     pub fn with_store(width: usize, height: usize, store: S) -> Self
     where
@@ -37,6 +40,40 @@ impl<T, S> GridBuf<T, S> {
         }
     }
 
+    /// Create a new grid using the provided storage, or return an error if the
+    /// length of `store` is not equal to `width * height`.
+    ///
+    /// ```
+    /// # use grid::{GridBufError, VecGrid};
+    /// let grid = VecGrid::try_with_store(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(grid.as_slice(), &[1, 2, 3, 4]);
+    ///
+    /// let err = VecGrid::try_with_store(2, 2, vec![1, 2, 3]).unwrap_err();
+    /// assert_eq!(err, GridBufError::StoreLength { expected: 4, found: 3 });
+    ///
+    /// let err = VecGrid::<u8>::try_with_store(usize::MAX, 2, vec![]).unwrap_err();
+    /// assert_eq!(err, GridBufError::Overflow { width: usize::MAX, height: 2 });
+    /// ```
+    #[inline]
+    pub fn try_with_store(width: usize, height: usize, store: S) -> Result<Self, GridBufError>
+    where
+        S: AsRef<[T]>,
+    {
+        let expected = width
+            .checked_mul(height)
+            .ok_or(GridBufError::Overflow { width, height })?;
+        let found = store.as_ref().len();
+        if found != expected {
+            return Err(GridBufError::StoreLength { expected, found });
+        }
+        Ok(Self {
+            width,
+            height,
+            store,
+            marker: PhantomData,
+        })
+    }
+
     /// Get the contents of the grid as a slice.
     #[inline]
     pub fn as_slice(&self) -> &[T]
@@ -60,6 +97,72 @@ impl<T, S> GridBuf<T, S> {
     pub fn to_store(self) -> S {
         self.store
     }
+
+    /// Drop the grid and return its `(width, height, store)`.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// let grid = VecGrid::with_store(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let (w, h, store) = grid.into_parts();
+    /// assert_eq!((w, h), (2, 3));
+    ///
+    /// let grid = VecGrid::from_parts(w, h, store).unwrap();
+    /// assert_eq!(grid.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn into_parts(self) -> (usize, usize, S) {
+        (self.width, self.height, self.store)
+    }
+
+    /// Create a new grid from the parts returned by [`into_parts`](Self::into_parts).
+    /// This is the same as [`try_with_store`](Self::try_with_store).
+    #[inline]
+    pub fn from_parts(width: usize, height: usize, store: S) -> Result<Self, GridBufError>
+    where
+        S: AsRef<[T]>,
+    {
+        Self::try_with_store(width, height, store)
+    }
+
+    /// Returns the layout of column `x` within the grid's storage as `(start, stride, len)`,
+    /// so that its values are at `start`, `start + stride`, and so on for `len` values. This
+    /// is `None` if the column is out of bounds.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// let grid = VecGrid::with_store(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let (start, stride, len) = grid.col_stride(1).unwrap();
+    /// assert_eq!((start, stride, len), (1, 3, 2));
+    ///
+    /// let col: Vec<_> = grid.as_slice()[start..].iter().step_by(stride).take(len).collect();
+    /// assert_eq!(col, vec![&2, &5]);
+    /// assert_eq!(grid.col_stride(3), None);
+    /// ```
+    #[inline]
+    pub fn col_stride(&self, x: usize) -> Option<(usize, usize, usize)> {
+        (x < self.width).then_some((x, self.width, self.height))
+    }
+
+    /// Change the width and height of the grid without moving any of its values, so the
+    /// same storage is read with a different row length. Panics if `width * height` is not
+    /// equal to the length of the storage.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let mut grid = VecGrid::with_store(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.reshape(2, 3);
+    /// assert_eq!(grid.size(), (2, 3));
+    /// assert_eq!(grid.row_slice(1), Some(&[3, 4][..]));
+    /// ```
+    #[inline]
+    pub fn reshape(&mut self, width: usize, height: usize)
+    where
+        S: AsRef<[T]>,
+    {
+        assert_eq!(width.checked_mul(height), Some(self.store.as_ref().len()));
+        self.width = width;
+        self.height = height;
+    }
 }
 
 impl<T> VecGrid<T> {
@@ -85,8 +188,418 @@ impl<T> VecGrid<T> {
     {
         Self::new_with(width, height, T::default)
     }
+
+    /// Create a new `VecGrid`, calling `f(x, y)` to produce the value of each cell.
+    /// The function is called once per cell, in row-major order.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// let grid = VecGrid::from_fn(3, 2, |x, y| (x + y) % 2);
+    /// assert_eq!(grid.as_slice(), &[
+    ///     0, 1, 0,
+    ///     1, 0, 1,
+    /// ]);
+    ///
+    /// let mut calls = Vec::new();
+    /// let table = VecGrid::from_fn(3, 3, |x, y| {
+    ///     calls.push((x, y));
+    ///     (x + 1) * (y + 1)
+    /// });
+    /// assert_eq!(table.as_slice(), &[
+    ///     1, 2, 3,
+    ///     2, 4, 6,
+    ///     3, 6, 9,
+    /// ]);
+    /// assert_eq!(&calls[..4], &[(0, 0), (1, 0), (2, 0), (0, 1)]);
+    /// ```
+    #[inline]
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        let len = width.checked_mul(height).expect("grid capacity overflow");
+        let mut store = Vec::with_capacity(len);
+        for y in 0..height {
+            for x in 0..width {
+                store.push(f(x, y));
+            }
+        }
+        Self {
+            width,
+            height,
+            store,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create a new `VecGrid` from a multi-line string, where each line is a row and each
+    /// character is converted to a cell's value with `f`. A trailing newline is ignored.
+    /// Returns an error if the lines are not all the same length.
+    ///
+    /// ```
+    /// # use grid::{ParseGridError, VecGrid};
+    /// let maze = VecGrid::from_lines("#.#\n...\n#.#\n", |c| c == '#').unwrap();
+    /// assert_eq!(maze.as_slice(), &[
+    ///     true, false, true,
+    ///     false, false, false,
+    ///     true, false, true,
+    /// ]);
+    ///
+    /// let err = VecGrid::from_lines("##\n##\n#", |c| c).unwrap_err();
+    /// assert_eq!(err, ParseGridError { line: 3, expected: 2, found: 1 });
+    /// ```
+    pub fn from_lines<F: FnMut(char) -> T>(s: &str, mut f: F) -> Result<Self, ParseGridError> {
+        let mut store = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for (i, line) in s.lines().enumerate() {
+            let len = store.len();
+            store.extend(line.chars().map(&mut f));
+            let found = store.len() - len;
+            if i == 0 {
+                width = found;
+            } else if found != width {
+                return Err(ParseGridError {
+                    line: i + 1,
+                    expected: width,
+                    found,
+                });
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width,
+            height,
+            store,
+            marker: PhantomData,
+        })
+    }
+
+    /// Resize the grid, keeping every existing value at the same `(x, y)` position.
+    /// Values that no longer fit are dropped, and new cells are filled with clones
+    /// of `fill`.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let mut grid = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    /// grid.resize(3, 3, 0);
+    /// assert_eq!(grid.as_slice(), &[
+    ///     1, 2, 0,
+    ///     3, 4, 0,
+    ///     0, 0, 0,
+    /// ]);
+    ///
+    /// grid.resize(1, 2, 0);
+    /// assert_eq!(grid.as_slice(), &[1, 3]);
+    /// assert_eq!((grid.width(), grid.height()), (1, 2));
+    /// ```
+    pub fn resize(&mut self, width: usize, height: usize, fill: T)
+    where
+        T: Clone,
+    {
+        let len = width.checked_mul(height).expect("grid capacity overflow");
+        if width != self.width {
+            let old = std::mem::take(&mut self.store);
+            let mut old = old.into_iter();
+            self.store.reserve_exact(len);
+            for _ in 0..height.min(self.height) {
+                self.store.extend(old.by_ref().take(self.width).take(width));
+                if self.width > width {
+                    old.by_ref().take(self.width - width).for_each(drop);
+                }
+                for _ in self.width..width {
+                    self.store.push(fill.clone());
+                }
+            }
+        }
+        self.store.resize(len, fill);
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Drop the grid and return its values as a list of rows, moving them out without
+    /// cloning.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// let grid = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    /// let rows = grid.into_rows();
+    /// assert_eq!(rows, vec![vec![1, 2], vec![3, 4]]);
+    /// assert_eq!(VecGrid::from(rows), VecGrid::with_store(2, 2, vec![1, 2, 3, 4]));
+    /// ```
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let mut store = self.store.into_iter();
+        (0..self.height)
+            .map(|_| store.by_ref().take(self.width).collect())
+            .collect()
+    }
+
+    /// Create a grid from a sequence of rows, taking its width from the first row, or
+    /// return an error if any row's length doesn't match. No rows creates a `0 × 0` grid.
+    ///
+    /// ```
+    /// # use grid::{Grid, RowLengthError, VecGrid};
+    /// let nums = [[1, 2], [3, 4]];
+    /// let doubled = VecGrid::try_from_rows(
+    ///     nums.rows().map(|row| row.iter().map(|n| n * 2).collect()),
+    /// );
+    /// assert_eq!(doubled, Ok(VecGrid::with_store(2, 2, vec![2, 4, 6, 8])));
+    ///
+    /// let err = VecGrid::try_from_rows([vec![1, 2], vec![3, 4], vec![5]]).unwrap_err();
+    /// assert_eq!(err, RowLengthError { row: 2, expected: 2, found: 1 });
+    ///
+    /// let empty = VecGrid::<i32>::try_from_rows([]).unwrap();
+    /// assert_eq!(empty.size(), (0, 0));
+    /// ```
+    pub fn try_from_rows<I>(rows: I) -> Result<Self, RowLengthError>
+    where
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        let mut grid = Self::with_store(0, 0, Vec::new());
+        for (y, row) in rows.into_iter().enumerate() {
+            if y > 0 && row.len() != grid.width {
+                return Err(RowLengthError {
+                    row: y,
+                    expected: grid.width,
+                    found: row.len(),
+                });
+            }
+            grid.push_row(row);
+        }
+        Ok(grid)
+    }
+
+    /// Create a grid from a sequence of rows, taking its width from the first row. Panics
+    /// if any row's length doesn't match; see [`try_from_rows`](Self::try_from_rows) for
+    /// a checked version.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// let grid = VecGrid::from_rows((0..3).map(|y| vec![y; 2]));
+    /// assert_eq!(grid.as_slice(), &[0, 0, 1, 1, 2, 2]);
+    /// ```
+    #[inline]
+    pub fn from_rows<I>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        Self::try_from_rows(rows).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Append a row to the bottom of the grid. If the grid has no rows, its width is set
+    /// to the length of `row`, otherwise this panics if the length of `row` does not match
+    /// the grid's width.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// let mut grid = VecGrid::new(0, 0);
+    /// for line in ["#.#", "..."] {
+    ///     grid.push_row(line.chars());
+    /// }
+    /// assert_eq!(grid, VecGrid::with_store(3, 2, "#.#...".chars().collect()));
+    /// ```
+    pub fn push_row<I: IntoIterator<Item = T>>(&mut self, row: I) {
+        let len = self.store.len();
+        self.store.extend(row);
+        let found = self.store.len() - len;
+        if self.height == 0 {
+            self.width = found;
+        } else if found != self.width {
+            self.store.truncate(len);
+            panic!(
+                "row length (is {found}) should be equal to width (is {})",
+                self.width
+            );
+        }
+        self.height += 1;
+    }
+
+    /// Insert a new row at `y`, filled with clones of `fill`, shifting all rows after it
+    /// down. Panics if `y` is greater than the grid's height.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// let mut grid = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    /// grid.insert_row(1, 0);
+    /// assert_eq!(grid, VecGrid::with_store(2, 3, vec![1, 2, 0, 0, 3, 4]));
+    /// grid.insert_row(3, 9);
+    /// assert_eq!(grid, VecGrid::with_store(2, 4, vec![1, 2, 0, 0, 3, 4, 9, 9]));
+    /// ```
+    pub fn insert_row(&mut self, y: usize, fill: T)
+    where
+        T: Clone,
+    {
+        assert!(
+            y <= self.height,
+            "row index (is {y}) should be <= height (is {})",
+            self.height
+        );
+        let i = y * self.width;
+        self.store
+            .splice(i..i, std::iter::repeat_n(fill, self.width));
+        self.height += 1;
+    }
+
+    /// Remove the row at `y`, shifting all rows after it up. Panics if `y` is out of
+    /// bounds.
+    ///
+    /// ```
+    /// # use grid::VecGrid;
+    /// let mut grid = VecGrid::with_store(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.remove_row(1);
+    /// assert_eq!(grid, VecGrid::with_store(2, 2, vec![1, 2, 5, 6]));
+    /// ```
+    pub fn remove_row(&mut self, y: usize) {
+        assert!(
+            y < self.height,
+            "row index (is {y}) should be < height (is {})",
+            self.height
+        );
+        let i = y * self.width;
+        self.store.drain(i..(i + self.width));
+        self.height -= 1;
+    }
+
+    /// Insert a new column at `x`, filled with clones of `fill`, shifting all columns after
+    /// it to the right. Panics if `x` is greater than the grid's width.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let mut grid = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    /// grid.insert_col(1, 0);
+    /// assert_eq!(grid, VecGrid::with_store(3, 2, vec![1, 0, 2, 3, 0, 4]));
+    /// assert_eq!(grid.row_slice(1), Some(&[3, 0, 4][..]));
+    /// ```
+    pub fn insert_col(&mut self, x: usize, fill: T)
+    where
+        T: Clone,
+    {
+        assert!(
+            x <= self.width,
+            "column index (is {x}) should be <= width (is {})",
+            self.width
+        );
+        let old = std::mem::take(&mut self.store);
+        let mut old = old.into_iter();
+        self.store.reserve_exact((self.width + 1) * self.height);
+        for _ in 0..self.height {
+            self.store.extend(old.by_ref().take(x));
+            self.store.push(fill.clone());
+            self.store.extend(old.by_ref().take(self.width - x));
+        }
+        self.width += 1;
+    }
+
+    /// Remove the column at `x`, shifting all columns after it to the left. Panics if `x`
+    /// is out of bounds.
+    ///
+    /// ```
+    /// # use grid::{Grid, VecGrid};
+    /// let mut grid = VecGrid::with_store(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.remove_col(0);
+    /// assert_eq!(grid, VecGrid::with_store(2, 2, vec![2, 3, 5, 6]));
+    /// assert_eq!(grid.row_slice(1), Some(&[5, 6][..]));
+    /// ```
+    pub fn remove_col(&mut self, x: usize) {
+        assert!(
+            x < self.width,
+            "column index (is {x}) should be < width (is {})",
+            self.width
+        );
+        let w = self.width;
+        let mut i = 0;
+        self.store.retain(|_| {
+            let keep = i % w != x;
+            i += 1;
+            keep
+        });
+        self.width -= 1;
+    }
+}
+
+/// The error returned by [`VecGrid::from_lines`] when a line's length does not match
+/// the width of the grid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseGridError {
+    /// The line number (starting from 1) of the offending line.
+    pub line: usize,
+    /// The expected length of the line, taken from the first line.
+    pub expected: usize,
+    /// The actual length of the line.
+    pub found: usize,
 }
 
+impl Display for ParseGridError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {} has a length of {}, expected {}",
+            self.line, self.found, self.expected
+        )
+    }
+}
+
+impl Error for ParseGridError {}
+
+/// The error returned by [`VecGrid::try_from_rows`] when a row's length does not match
+/// the width of the grid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RowLengthError {
+    /// The index (starting from 0) of the offending row.
+    pub row: usize,
+    /// The expected length of the row, taken from the first row.
+    pub expected: usize,
+    /// The actual length of the row.
+    pub found: usize,
+}
+
+impl Display for RowLengthError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} has a length of {}, expected {}",
+            self.row, self.found, self.expected
+        )
+    }
+}
+
+impl Error for RowLengthError {}
+
+/// The error returned by [`GridBuf::try_with_store`] when the storage does not fit
+/// the requested size.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GridBufError {
+    /// `width * height` overflowed `usize`.
+    Overflow {
+        /// The requested width.
+        width: usize,
+        /// The requested height.
+        height: usize,
+    },
+    /// The length of the storage was not equal to `width * height`.
+    StoreLength {
+        /// The expected length, `width * height`.
+        expected: usize,
+        /// The actual length of the storage.
+        found: usize,
+    },
+}
+
+impl Display for GridBufError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Overflow { width, height } => {
+                write!(f, "grid size {width}x{height} overflows usize")
+            }
+            Self::StoreLength { expected, found } => {
+                write!(f, "store has a length of {found}, expected {expected}")
+            }
+        }
+    }
+}
+
+impl Error for GridBufError {}
+
 impl<'a, T> SliceGrid<'a, T> {
     /// Create a new `SliceGrid` from the provided slice. Panics if the length of
     /// the slice is not exactly `width * height`.
@@ -103,6 +616,24 @@ impl<T, const N: usize> ArrGrid<T, N> {
         Self::with_store(width, height, std::array::from_fn(|_| fill()))
     }
 
+    /// Create a new `ArrGrid`, calling `f(x, y)` to produce the value of each cell in
+    /// row-major order. Panics if `N` is not equal to `width * height`.
+    ///
+    /// ```
+    /// # use grid::ArrGrid;
+    /// let grid = ArrGrid::<_, 6>::from_fn(2, 3, |x, y| x + y * 2);
+    /// assert_eq!(grid.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        assert_eq!(width.checked_mul(height), Some(N));
+        Self::with_store(
+            width,
+            height,
+            std::array::from_fn(|i| f(i % width, i / width)),
+        )
+    }
+
     /// Create a new `ArrGrid` fill with default values.
     #[inline]
     pub fn new(width: usize, height: usize) -> Self
@@ -160,6 +691,11 @@ impl<T, S: AsRef<[T]>> Grid for GridBuf<T, S> {
         y.checked_mul(self.width)
             .and_then(|i| self.as_slice().get(i..(i + self.width)))
     }
+
+    #[inline]
+    fn contiguous_slice(&self) -> Option<&[Self::Item]> {
+        Some(self.as_slice())
+    }
 }
 
 impl<T, S: AsRef<[T]> + AsMut<[T]>> GridMut for GridBuf<T, S> {
@@ -189,6 +725,23 @@ impl<T, S: AsRef<[T]> + AsMut<[T]>> GridMut for GridBuf<T, S> {
         y.checked_mul(w)
             .and_then(|i| self.as_mut_slice().get_mut(i..(i + w)))
     }
+
+    #[inline]
+    fn contiguous_slice_mut(&mut self) -> Option<&mut [Self::Item]> {
+        Some(self.as_mut_slice())
+    }
+}
+
+/// Creates a grid from a list of rows. Panics if the rows are not all the same length.
+impl<T> From<Vec<Vec<T>>> for VecGrid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let len = rows.first().map_or(0, |row| row.len() * rows.len());
+        let mut grid = Self::with_store(0, 0, Vec::with_capacity(len));
+        for row in rows {
+            grid.push_row(row);
+        }
+        grid
+    }
 }
 
 impl<'a, T, S: AsRef<[T]>> IntoIterator for &'a GridBuf<T, S> {
@@ -223,6 +776,61 @@ impl<T, S: Clone> Clone for GridBuf<T, S> {
     }
 }
 
+/// Grids are equal if they have the same size and values, regardless of what kind of
+/// storage they use.
+///
+/// ```
+/// # use grid::{ArrGrid, VecGrid};
+/// let a = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+/// let b = ArrGrid::with_store(2, 2, [1, 2, 3, 4]);
+/// let c = VecGrid::with_store(4, 1, vec![1, 2, 3, 4]);
+/// assert_eq!(a, b);
+/// assert_ne!(a, c);
+/// ```
+impl<T, U, S, S2> PartialEq<GridBuf<U, S2>> for GridBuf<T, S>
+where
+    T: PartialEq<U>,
+    S: AsRef<[T]>,
+    S2: AsRef<[U]>,
+{
+    #[inline]
+    fn eq(&self, other: &GridBuf<U, S2>) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, S: AsRef<[T]>> Eq for GridBuf<T, S> {}
+
+/// Hashes the grid through [`Grid::content_hash`], so it hashes the same as any other grid
+/// with the same size and values, whatever its storage.
+///
+/// ```
+/// # use grid::{ArrGrid, Grid, VecGrid};
+/// # use std::hash::{BuildHasher, Hasher, RandomState};
+/// let mut vec = Vec::with_capacity(100);
+/// vec.extend([1, 2, 3, 4]);
+/// let a = VecGrid::with_store(2, 2, vec);
+/// let b = ArrGrid::with_store(2, 2, [1, 2, 3, 4]);
+/// let c = VecGrid::with_store(4, 1, vec![1, 2, 3, 4]);
+///
+/// let state = RandomState::new();
+/// assert_eq!(state.hash_one(&a), state.hash_one(&b));
+/// assert_eq!(state.hash_one(&a), {
+///     let mut hasher = state.build_hasher();
+///     [[1, 2], [3, 4]].content_hash(&mut hasher);
+///     hasher.finish()
+/// });
+/// assert_ne!(state.hash_one(&a), state.hash_one(&c));
+/// ```
+impl<T: Hash, S: AsRef<[T]>> Hash for GridBuf<T, S> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.content_hash(state);
+    }
+}
+
 impl<T: Debug, S: AsRef<[T]>> Debug for GridBuf<T, S> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -230,6 +838,20 @@ impl<T: Debug, S: AsRef<[T]>> Debug for GridBuf<T, S> {
     }
 }
 
+/// Displays the grid's values in rows, with no separator between values.
+///
+/// ```
+/// # use grid::VecGrid;
+/// let grid = VecGrid::with_store(3, 2, "#.##.#".chars().collect());
+/// assert_eq!(grid.to_string(), "#.#\n#.#");
+/// ```
+impl<T: Display, S: AsRef<[T]>> Display for GridBuf<T, S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.display_fmt(f)
+    }
+}
+
 impl<C: Coord, T, S: AsRef<[T]>> Index<C> for GridBuf<T, S> {
     type Output = T;
 
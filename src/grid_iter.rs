@@ -7,12 +7,45 @@ pub struct GridIter<GridRef> {
     grid: GridRef,
     x: usize,
     y: usize,
+    len: usize,
 }
 
 impl<GridRef> GridIter<GridRef> {
     #[inline]
-    pub(crate) fn new(grid: GridRef) -> Self {
-        Self { grid, x: 0, y: 0 }
+    pub(crate) fn new(grid: GridRef, len: usize) -> Self {
+        Self {
+            grid,
+            x: 0,
+            y: 0,
+            len,
+        }
+    }
+
+    /// Returns the position of the next value from the front, and advances.
+    #[inline]
+    fn advance(&mut self, w: usize) -> Option<(usize, usize)> {
+        if self.len == 0 {
+            return None;
+        }
+        let (x, y) = (self.x, self.y);
+        self.len -= 1;
+        self.x += 1;
+        if self.x == w {
+            self.x = 0;
+            self.y += 1;
+        }
+        Some((x, y))
+    }
+
+    /// Returns the position of the next value from the back, and advances.
+    #[inline]
+    fn advance_back(&mut self, w: usize) -> Option<(usize, usize)> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let i = self.y * w + self.x + self.len;
+        Some((i % w, i / w))
     }
 }
 
@@ -21,21 +54,13 @@ impl<'a, G: Grid> Iterator for GridIter<&'a G> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let val = self.grid.get(self.x, self.y)?;
-        let x = self.x;
-        let y = self.y;
-        self.x += 1;
-        if self.x == self.grid.width() {
-            self.x = 0;
-            self.y += 1;
-        }
-        Some((val, x, y))
+        let (x, y) = self.advance(self.grid.width())?;
+        Some((self.grid.get(x, y)?, x, y))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.len();
-        (len, Some(len))
+        (self.len, Some(self.len))
     }
 
     #[inline]
@@ -43,19 +68,20 @@ impl<'a, G: Grid> Iterator for GridIter<&'a G> {
     where
         Self: Sized,
     {
-        self.len()
+        self.len
     }
 }
 
-impl<G: Grid> ExactSizeIterator for GridIter<&G> {
+impl<G: Grid> DoubleEndedIterator for GridIter<&G> {
     #[inline]
-    fn len(&self) -> usize {
-        let w = self.grid.width();
-        let h = self.grid.height();
-        (h.saturating_sub(self.y + 1)) * w + (w - self.x)
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.advance_back(self.grid.width())?;
+        Some((self.grid.get(x, y)?, x, y))
     }
 }
 
+impl<G: Grid> ExactSizeIterator for GridIter<&G> {}
+
 impl<G: Grid> FusedIterator for GridIter<&G> {}
 
 impl<'a, G: GridMut> Iterator for GridIter<&'a mut G> {
@@ -63,14 +89,8 @@ impl<'a, G: GridMut> Iterator for GridIter<&'a mut G> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let val: *mut G::Item = self.grid.get_mut(self.x, self.y)?;
-        let x = self.x;
-        let y = self.y;
-        self.x += 1;
-        if self.x == self.grid.width() {
-            self.x = 0;
-            self.y += 1;
-        }
+        let (x, y) = self.advance(self.grid.width())?;
+        let val: *mut G::Item = self.grid.get_mut(x, y)?;
         // SAFETY: this iterator has a mutable reference to the grid, so as long as it exists, the
         // mutable reference to the value fetched from inside the grid will also be valid.
         Some((unsafe { &mut *val }, x, y))
@@ -78,8 +98,7 @@ impl<'a, G: GridMut> Iterator for GridIter<&'a mut G> {
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.len();
-        (len, Some(len))
+        (self.len, Some(self.len))
     }
 
     #[inline]
@@ -87,17 +106,21 @@ impl<'a, G: GridMut> Iterator for GridIter<&'a mut G> {
     where
         Self: Sized,
     {
-        self.len()
+        self.len
     }
 }
 
-impl<G: GridMut> ExactSizeIterator for GridIter<&mut G> {
+impl<G: GridMut> DoubleEndedIterator for GridIter<&mut G> {
     #[inline]
-    fn len(&self) -> usize {
-        let w = self.grid.width();
-        let h = self.grid.height();
-        (h.saturating_sub(self.y + 1)) * w + (w - self.x)
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.advance_back(self.grid.width())?;
+        let val: *mut G::Item = self.grid.get_mut(x, y)?;
+        // SAFETY: each position is only ever yielded once from either end, so the mutable
+        // references handed out never alias.
+        Some((unsafe { &mut *val }, x, y))
     }
 }
 
+impl<G: GridMut> ExactSizeIterator for GridIter<&mut G> {}
+
 impl<G: GridMut> FusedIterator for GridIter<&mut G> {}
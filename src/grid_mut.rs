@@ -1,5 +1,10 @@
 use crate::cols_iter::ColsIter;
-use crate::{Col, Coord, CoordComponent, Grid, GridIter, Row, RowsIter, View};
+use crate::grid::bound_to_range;
+use crate::{
+    Clamp, Col, Coord, CoordComponent, FlipX, FlipY, Grid, GridIter, Rotate90, Row, RowsIter,
+    TilesIter, Torus, Transpose, View, Wrap, ZipIter,
+};
+use std::ops::RangeBounds;
 
 /// A type representing a mutable 2D array.
 pub trait GridMut: Grid {
@@ -36,8 +41,54 @@ pub trait GridMut: Grid {
         )
     }
 
+    /// Returns a mutable reference to the value at `(x, y)`, wrapping coordinates that are
+    /// out of bounds around to the other side of the grid. Panics if the grid is empty.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 3]; 2];
+    /// *nums.get_wrapped_mut(-1, 0) = 1;
+    /// *nums.get_wrapped_mut(4, -3) = 2;
+    /// assert_eq!(nums, [
+    ///     [0, 0, 1],
+    ///     [0, 2, 0],
+    /// ]);
+    /// ```
+    #[inline]
+    fn get_wrapped_mut(&mut self, x: i32, y: i32) -> &mut Self::Item {
+        self.get_mut_at(Wrap((x, y)))
+            .expect("cannot sample an empty grid")
+    }
+
+    /// Returns a mutable reference to the value at `(x, y)`, clamping coordinates that are
+    /// out of bounds to the nearest edge of the grid. Panics if the grid is empty.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 3]; 2];
+    /// *nums.get_clamped_mut(-1, 0) = 1;
+    /// *nums.get_clamped_mut(4, 1) = 2;
+    /// assert_eq!(nums, [
+    ///     [1, 0, 0],
+    ///     [0, 0, 2],
+    /// ]);
+    /// ```
+    #[inline]
+    fn get_clamped_mut(&mut self, x: i32, y: i32) -> &mut Self::Item {
+        self.get_mut_at(Clamp((x, y)))
+            .expect("cannot sample an empty grid")
+    }
+
     /// Returns a mutable reference to the value stored at the provided coordinate
     /// in the grid, skipping any bounds checks.
+    ///
+    /// For a safe alternative, see [`get_mut_at`](Self::get_mut_at).
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds coord is *[undefined behavior]*
+    /// even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     #[inline]
     unsafe fn get_unchecked_mut_at(&mut self, coord: impl Coord) -> &mut Self::Item {
         self.get_unchecked_mut(
@@ -54,6 +105,14 @@ pub trait GridMut: Grid {
     /// [`copy_from_slice`]: https://doc.rust-lang.org/std/primitive.slice.html#method.copy_from_slice
     fn row_slice_mut(&mut self, y: usize) -> Option<&mut [Self::Item]>;
 
+    /// Returns the entire grid as a single mutable slice in row-major order if it is able
+    /// to do so. For example, [`fill`](Self::fill) uses this to fill contiguous grids with
+    /// a single call to std's [`slice::fill`]. By default this returns `None`.
+    #[inline]
+    fn contiguous_slice_mut(&mut self) -> Option<&mut [Self::Item]> {
+        None
+    }
+
     /// Returns row `y` of the grid as a mutable slice if it is able to do so. This variation
     /// can take signed integers, or y-values in a [`Wrap`](crate::Wrap) or
     /// [`Clamp`](crate::Clamp).
@@ -70,6 +129,24 @@ pub trait GridMut: Grid {
             .map(|curr| std::mem::replace(curr, value))
     }
 
+    /// Replace the value stored at the provided coordinate in the grid. If the coordinate
+    /// was out of bounds, `None` is returned, otherwise the replaced value is returned.
+    ///
+    /// ```
+    /// # use grid::{Clamp, GridMut, VecGrid, Wrap};
+    /// let mut nums = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(nums.set_at(Wrap((-1, -1)), 5), Some(4));
+    /// assert_eq!(nums.set_at(Clamp((-1, 3)), 6), Some(3));
+    /// assert_eq!(nums.set_at((2, 0), 7), None);
+    /// assert_eq!(nums.as_slice(), &[1, 2, 6, 5]);
+    /// ```
+    #[inline]
+    fn set_at(&mut self, coord: impl Coord, value: Self::Item) -> Option<Self::Item> {
+        self.get_mut_at(coord)
+            .map(|curr| std::mem::replace(curr, value))
+    }
+
     /// Replace the value stored at `(x, y)` in the grid, without bounds checking, and
     /// return the replaced value.
     ///
@@ -85,8 +162,343 @@ pub trait GridMut: Grid {
         std::mem::replace(self.get_unchecked_mut(x, y), value)
     }
 
+    /// Swap the values stored at coordinates `a` and `b`, returning `true` if both were in
+    /// bounds. If either coordinate is out of bounds, the grid is left unchanged and `false`
+    /// is returned.
+    ///
+    /// ```
+    /// # use grid::{GridMut, VecGrid};
+    /// let mut nums = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// assert!(nums.swap((0, 0), (1, 1)));
+    /// assert!(nums.swap((1, 0), (0, 1)));
+    /// assert_eq!(nums.as_slice(), &[4, 3, 2, 1]);
+    ///
+    /// assert!(nums.swap((1, 1), (1, 1)));
+    /// assert!(!nums.swap((0, 0), (2, 2)));
+    /// assert_eq!(nums.as_slice(), &[4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    fn swap(&mut self, a: (usize, usize), b: (usize, usize)) -> bool {
+        let (w, h) = (self.width(), self.height());
+        if a.0 >= w || a.1 >= h || b.0 >= w || b.1 >= h {
+            return false;
+        }
+        if a == b {
+            return true;
+        }
+        if let Some(slice) = self.contiguous_slice_mut() {
+            slice.swap(a.1 * w + a.0, b.1 * w + b.0);
+        } else if let Some(row) = (a.1 == b.1).then(|| self.row_slice_mut(a.1)).flatten() {
+            row.swap(a.0, b.0);
+        } else {
+            // SAFETY: both coordinates were bounds-checked above. Each reference is taken from
+            // its own borrow of the grid and only used before the next one is taken, and the
+            // value read out of `a` is written back into `b`, so nothing is dropped twice.
+            unsafe {
+                let val = std::ptr::read(self.get_unchecked_mut(a.0, a.1));
+                let val = std::mem::replace(self.get_unchecked_mut(b.0, b.1), val);
+                std::ptr::write(self.get_unchecked_mut(a.0, a.1), val);
+            }
+        }
+        true
+    }
+
+    /// Swap the values of rows `a` and `b`. Panics if either row is out of bounds, and does
+    /// nothing if `a == b`.
+    ///
+    /// ```
+    /// # use grid::{GridMut, VecGrid};
+    /// let mut nums = VecGrid::with_store(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// nums.swap_rows(0, 2);
+    /// assert_eq!(nums.as_slice(), &[5, 6, 3, 4, 1, 2]);
+    /// ```
+    #[inline]
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let h = self.height();
+        assert!(a < h && b < h, "row out of bounds");
+        if a == b {
+            return;
+        }
+        let w = self.width();
+        if let Some(slice) = self.contiguous_slice_mut() {
+            let (lo, hi) = (a.min(b), a.max(b));
+            let (top, bottom) = slice.split_at_mut(hi * w);
+            top[lo * w..(lo + 1) * w].swap_with_slice(&mut bottom[..w]);
+            return;
+        }
+        for x in 0..w {
+            self.swap((x, a), (x, b));
+        }
+    }
+
+    /// Swap the values of columns `a` and `b`. Panics if either column is out of bounds, and
+    /// does nothing if `a == b`.
+    ///
+    /// ```
+    /// # use grid::{GridMut, VecGrid};
+    /// let mut nums = VecGrid::with_store(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// nums.swap_cols(0, 2);
+    /// assert_eq!(nums.as_slice(), &[3, 2, 1, 6, 5, 4]);
+    /// ```
+    #[inline]
+    fn swap_cols(&mut self, a: usize, b: usize) {
+        let w = self.width();
+        assert!(a < w && b < w, "column out of bounds");
+        if a == b {
+            return;
+        }
+        for y in 0..self.height() {
+            if let Some(row) = self.row_slice_mut(y) {
+                row.swap(a, b);
+            } else {
+                self.swap((a, y), (b, y));
+            }
+        }
+    }
+
+    /// Mirror the grid horizontally in place, so that its x-axis is reversed.
+    ///
+    /// ```
+    /// # use grid::{GridMut, VecGrid};
+    /// let mut nums = VecGrid::with_store(4, 2, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    /// nums.view_mut(1, 0, 3, 2).flip_x_in_place();
+    /// assert_eq!(nums.as_slice(), &[1, 4, 3, 2, 5, 8, 7, 6]);
+    /// ```
+    #[inline]
+    fn flip_x_in_place(&mut self) {
+        let w = self.width();
+        for y in 0..self.height() {
+            if let Some(row) = self.row_slice_mut(y) {
+                row.reverse();
+            } else {
+                for x in 0..w / 2 {
+                    self.swap((x, y), (w - 1 - x, y));
+                }
+            }
+        }
+    }
+
+    /// Mirror the grid vertically in place, so that its y-axis is reversed.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [1, 2],
+    ///     [3, 4],
+    ///     [5, 6],
+    /// ];
+    /// nums.flip_y_in_place();
+    /// assert_eq!(nums, [
+    ///     [5, 6],
+    ///     [3, 4],
+    ///     [1, 2],
+    /// ]);
+    /// ```
+    #[inline]
+    fn flip_y_in_place(&mut self) {
+        let h = self.height();
+        for y in 0..h / 2 {
+            self.swap_rows(y, h - 1 - y);
+        }
+    }
+
+    /// Rotate a square grid 90° clockwise in place. Panics if the grid is not square.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut piece = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ];
+    /// piece.rotate_cw_in_place();
+    /// assert_eq!(piece, [
+    ///     [7, 4, 1],
+    ///     [8, 5, 2],
+    ///     [9, 6, 3],
+    /// ]);
+    ///
+    /// let mut one = [[1]];
+    /// one.rotate_cw_in_place();
+    /// assert_eq!(one, [[1]]);
+    ///
+    /// let mut two = [[1, 2], [3, 4]];
+    /// two.rotate_cw_in_place();
+    /// assert_eq!(two, [[3, 1], [4, 2]]);
+    ///
+    /// let orig = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]];
+    /// let mut four = orig;
+    /// four.rotate_cw_in_place();
+    /// assert!(four.content_eq(&orig.rotate_cw_view()));
+    /// ```
+    #[inline]
+    fn rotate_cw_in_place(&mut self) {
+        let n = self.width();
+        assert_eq!(n, self.height(), "grid is not square");
+        for y in 0..n / 2 {
+            for x in y..n - 1 - y {
+                self.swap((x, y), (n - 1 - y, x));
+                self.swap((x, y), (n - 1 - x, n - 1 - y));
+                self.swap((x, y), (y, n - 1 - x));
+            }
+        }
+    }
+
+    /// Rotate a square grid 90° counter-clockwise in place. Panics if the grid is not
+    /// square.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut piece = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ];
+    /// piece.rotate_ccw_in_place();
+    /// assert_eq!(piece, [
+    ///     [3, 6, 9],
+    ///     [2, 5, 8],
+    ///     [1, 4, 7],
+    /// ]);
+    ///
+    /// let mut one = [[1]];
+    /// one.rotate_ccw_in_place();
+    /// assert_eq!(one, [[1]]);
+    ///
+    /// let mut two = [[1, 2], [3, 4]];
+    /// two.rotate_ccw_in_place();
+    /// assert_eq!(two, [[2, 4], [1, 3]]);
+    ///
+    /// let orig = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]];
+    /// let mut four = orig;
+    /// four.rotate_ccw_in_place();
+    /// assert!(four.content_eq(&orig.rotate_ccw_view()));
+    /// ```
+    #[inline]
+    fn rotate_ccw_in_place(&mut self) {
+        let n = self.width();
+        assert_eq!(n, self.height(), "grid is not square");
+        for y in 0..n / 2 {
+            for x in y..n - 1 - y {
+                self.swap((x, y), (y, n - 1 - x));
+                self.swap((x, y), (n - 1 - x, n - 1 - y));
+                self.swap((x, y), (n - 1 - y, x));
+            }
+        }
+    }
+
+    /// Move the contents of the grid by `(dx, dy)`. Values moved off the grid are dropped,
+    /// and the cells left behind are filled with clones of `fill`. Shifting by the size of
+    /// the grid or more fills the whole grid.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ];
+    /// nums.scroll(1, -1, 0);
+    /// assert_eq!(nums, [
+    ///     [0, 4, 5],
+    ///     [0, 7, 8],
+    ///     [0, 0, 0],
+    /// ]);
+    ///
+    /// nums.scroll(-3, 0, 0);
+    /// assert_eq!(nums, [[0; 3]; 3]);
+    ///
+    /// let mut nums = [[1; 3]; 2];
+    /// nums.scroll(0, 7, 0);
+    /// assert_eq!(nums, [[0; 3]; 2]);
+    ///
+    /// let mut nums = [[1; 3]; 2];
+    /// nums.scroll(-10, 1, 0);
+    /// assert_eq!(nums, [[0; 3]; 2]);
+    /// ```
+    #[inline]
+    fn scroll(&mut self, dx: isize, dy: isize, fill: Self::Item)
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        let (w, h) = (self.width(), self.height());
+        if dx.unsigned_abs() >= w || dy.unsigned_abs() >= h {
+            self.fill(fill);
+            return;
+        }
+        self.scroll_wrapping(dx, dy);
+        let (ax, ay) = (dx.unsigned_abs(), dy.unsigned_abs());
+        let x = if dx > 0 { 0 } else { w - ax };
+        let y = if dy > 0 { 0 } else { h - ay };
+        self.fill_rect(x, 0, ax, h, fill.clone());
+        self.fill_rect(0, y, w, ay, fill);
+    }
+
+    /// Move the contents of the grid by `(dx, dy)`, wrapping values that move off one edge
+    /// of the grid around to the opposite edge.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    /// nums.scroll_wrapping(-1, 3);
+    /// assert_eq!(nums, [
+    ///     [5, 6, 4],
+    ///     [2, 3, 1],
+    /// ]);
+    ///
+    /// // shifting by more than the size wraps around multiple times
+    /// nums.scroll_wrapping(7, -5);
+    /// assert_eq!(nums, [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    /// ```
+    #[inline]
+    fn scroll_wrapping(&mut self, dx: isize, dy: isize) {
+        let (w, h) = (self.width(), self.height());
+        if w == 0 || h == 0 {
+            return;
+        }
+        let dx = (dx as i128).rem_euclid(w as i128) as usize;
+        let dy = (dy as i128).rem_euclid(h as i128) as usize;
+        if dx > 0 {
+            for y in 0..h {
+                if let Some(row) = self.row_slice_mut(y) {
+                    row.rotate_right(dx);
+                } else {
+                    reverse_cells(self, y, 0, w);
+                    reverse_cells(self, y, 0, dx);
+                    reverse_cells(self, y, dx, w);
+                }
+            }
+        }
+        if dy > 0 {
+            reverse_rows(self, 0, h);
+            reverse_rows(self, 0, dy);
+            reverse_rows(self, dy, h);
+        }
+    }
+
     /// Get a mutable [`View`] into this grid, or `None` if the provided region is
     /// out of bounds.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut nums = [[0; 3]; 3];
+    ///
+    /// let mut view = nums.try_view_mut(1, 1, 2, 2).unwrap();
+    /// view.fill(1);
+    /// assert_eq!(nums, [[0, 0, 0], [0, 1, 1], [0, 1, 1]]);
+    ///
+    /// assert!(nums.try_view_mut(2, 2, 5, 5).is_none());
+    /// assert!(nums.try_view_mut(usize::MAX, 0, 2, 1).is_none());
+    /// assert!(nums.try_view_mut(0, 1, 1, usize::MAX).is_none());
+    /// ```
     #[inline]
     fn try_view_mut(
         &mut self,
@@ -112,13 +524,329 @@ pub trait GridMut: Grid {
             .expect("view does not overlap grid's bounds")
     }
 
-    /// Mutably iterate over all values in the grid, with their positions.
+    /// Get a mutable [`View`] into this grid covering the provided ranges of columns and
+    /// rows, or `None` if either range is out of bounds. This works like slicing a [`Vec`],
+    /// accepting any kind of range.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 4]; 3];
+    /// nums.view_range_mut(1..3, 1..).unwrap().fill(1);
+    /// assert_eq!(nums, [
+    ///     [0, 0, 0, 0],
+    ///     [0, 1, 1, 0],
+    ///     [0, 1, 1, 0],
+    /// ]);
+    /// assert!(nums.view_range_mut(..5, ..).is_none());
+    /// ```
+    #[inline]
+    fn view_range_mut(
+        &mut self,
+        cols: impl RangeBounds<usize>,
+        rows: impl RangeBounds<usize>,
+    ) -> Option<View<&mut Self::RootMut>> {
+        let (x, w) = bound_to_range(cols, self.width())?;
+        let (y, h) = bound_to_range(rows, self.height())?;
+        self.try_view_mut(x, y, w, h)
+    }
+
+    /// Mutably iterate over the grid split into non-overlapping `tw × th` tiles, in
+    /// row-major order. If the grid's size is not a multiple of the tile size, the tiles
+    /// along the right and bottom edges are clipped to fit. Panics if `tw` or `th` is zero.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [0, 0, 0, 0, 0],
+    ///     [0, 0, 0, 0, 0],
+    ///     [0, 0, 0, 0, 0],
+    /// ];
+    ///
+    /// for (i, mut tile) in nums.tiles_mut(2, 2).enumerate() {
+    ///     tile.fill(i);
+    /// }
+    /// assert_eq!(nums, [
+    ///     [0, 0, 1, 1, 2],
+    ///     [0, 0, 1, 1, 2],
+    ///     [3, 3, 4, 4, 5],
+    /// ]);
+    /// ```
+    #[inline]
+    fn tiles_mut(&mut self, tw: usize, th: usize) -> TilesIter<&mut Self>
+    where
+        Self: Sized,
+    {
+        TilesIter::new(self, tw, th)
+    }
+
+    /// Mutably iterate over the grid split into full-width bands that are `rows_per_band`
+    /// rows tall, from top to bottom. If the height is not a multiple of `rows_per_band`,
+    /// the last band is shorter. Panics if `rows_per_band` is zero.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 2]; 5];
+    /// for (i, mut band) in nums.row_bands_mut(2).enumerate() {
+    ///     band.fill(i);
+    /// }
+    /// assert_eq!(nums, [[0, 0], [0, 0], [1, 1], [1, 1], [2, 2]]);
+    /// ```
+    #[inline]
+    fn row_bands_mut(&mut self, rows_per_band: usize) -> TilesIter<&mut Self>
+    where
+        Self: Sized,
+    {
+        let w = self.width().max(1);
+        TilesIter::new(self, w, rows_per_band)
+    }
+
+    /// Split the grid into four non-overlapping mutable views around the pivot `(x, y)`,
+    /// returned in the order top-left, top-right, bottom-left, bottom-right. The pivot
+    /// may sit on the grid's edge, which produces empty quadrants. Panics if the pivot is
+    /// outside of the grid.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    ///
+    /// let (mut a, mut b, mut c, mut d) = nums.split_quadrants_mut(1, 2);
+    /// a.fill(1);
+    /// b.fill(2);
+    /// c.fill(3);
+    /// d.fill(4);
+    /// assert_eq!(nums, [
+    ///     [1, 2, 2],
+    ///     [1, 2, 2],
+    ///     [3, 4, 4],
+    /// ]);
+    ///
+    /// let (_, _, _, mut all) = nums.split_quadrants_mut(0, 0);
+    /// all.fill(0);
+    /// assert_eq!(nums, [[0; 3]; 3]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn split_quadrants_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+    ) -> (
+        View<&mut Self::RootMut>,
+        View<&mut Self::RootMut>,
+        View<&mut Self::RootMut>,
+        View<&mut Self::RootMut>,
+    ) {
+        assert!(
+            x <= self.width() && y <= self.height(),
+            "pivot is outside of the grid"
+        );
+        let (w, h) = (self.width(), self.height());
+        let (top, bottom) = self.view_mut(0, 0, w, h).split_at_row(y);
+        let (top_left, top_right) = top.split_at_col(x);
+        let (bottom_left, bottom_right) = bottom.split_at_col(x);
+        (top_left, top_right, bottom_left, bottom_right)
+    }
+
+    /// Get a mutable [`Transpose`] of this grid, which swaps its x and y axes without
+    /// copying anything.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut nums = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    ///
+    /// nums.transpose_view_mut().row_mut(1).fill(1);
+    /// assert_eq!(nums, [
+    ///     [0, 1, 0],
+    ///     [0, 1, 0],
+    /// ]);
+    /// ```
+    #[inline]
+    fn transpose_view_mut(&mut self) -> Transpose<&mut Self> {
+        Transpose::new(self)
+    }
+
+    /// Get a mutable [`Torus`] of this grid, whose edges wrap around to the opposite side
+    /// without copying anything. The torus has the same width and height as this grid.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut nums = [[0; 3]; 2];
+    /// let mut torus = nums.torus_view_mut();
+    /// *torus.get_mut(3, 3).unwrap() = 1;
+    /// *torus.get_mut(5, 0).unwrap() = 2;
+    /// assert_eq!(nums, [
+    ///     [0, 0, 2],
+    ///     [1, 0, 0],
+    /// ]);
+    /// ```
+    #[inline]
+    fn torus_view_mut(&mut self) -> Torus<&mut Self> {
+        Torus::new(self)
+    }
+
+    /// Get a mutable [`FlipX`] of this grid, which mirrors it horizontally without
+    /// copying anything.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut nums = [
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 0, 0],
+    /// ];
+    ///
+    /// nums.view_mut(1, 0, 3, 2).flip_x_view_mut().draw_copied(&[
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    /// assert_eq!(nums, [
+    ///     [0, 3, 2, 1],
+    ///     [0, 6, 5, 4],
+    /// ]);
+    /// ```
+    #[inline]
+    fn flip_x_view_mut(&mut self) -> FlipX<&mut Self> {
+        FlipX::new(self)
+    }
+
+    /// Get a mutable [`FlipY`] of this grid, which mirrors it vertically without
+    /// copying anything.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut nums = [
+    ///     [0, 0],
+    ///     [0, 0],
+    ///     [0, 0],
+    /// ];
+    ///
+    /// nums.flip_y_view_mut().draw_copied(&[
+    ///     [1, 2],
+    ///     [3, 4],
+    ///     [5, 6],
+    /// ]);
+    /// assert_eq!(nums, [
+    ///     [5, 6],
+    ///     [3, 4],
+    ///     [1, 2],
+    /// ]);
+    /// ```
+    #[inline]
+    fn flip_y_view_mut(&mut self) -> FlipY<&mut Self> {
+        FlipY::new(self)
+    }
+
+    /// Get a mutable [`Rotate90`] of this grid, which rotates it 90° clockwise without
+    /// copying anything.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut nums = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    ///
+    /// nums.rotate_cw_view_mut().row_mut(0).fill(1);
+    /// assert_eq!(nums, [
+    ///     [1, 0, 0],
+    ///     [1, 0, 0],
+    /// ]);
+    /// ```
+    #[inline]
+    fn rotate_cw_view_mut(&mut self) -> Rotate90<&mut Self> {
+        Rotate90::new(self, 1)
+    }
+
+    /// Get a mutable [`Rotate90`] of this grid, which rotates it 90° counter-clockwise
+    /// without copying anything.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut nums = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    ///
+    /// nums.rotate_ccw_view_mut().row_mut(0).fill(1);
+    /// assert_eq!(nums, [
+    ///     [0, 0, 1],
+    ///     [0, 0, 1],
+    /// ]);
+    /// ```
+    #[inline]
+    fn rotate_ccw_view_mut(&mut self) -> Rotate90<&mut Self> {
+        Rotate90::new(self, 3)
+    }
+
+    /// Get a mutable [`Rotate90`] of this grid, which rotates it 180° without copying
+    /// anything.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut};
+    /// let mut nums = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    ///
+    /// nums.rotate_180_view_mut().set(0, 0, 1);
+    /// assert_eq!(nums, [
+    ///     [0, 0, 0],
+    ///     [0, 0, 1],
+    /// ]);
+    /// ```
+    #[inline]
+    fn rotate_180_view_mut(&mut self) -> Rotate90<&mut Self> {
+        Rotate90::new(self, 2)
+    }
+
+    /// Mutably iterate over all values in the grid, with their positions. The iterator can
+    /// also be reversed to walk the grid from the bottom-right.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 3]; 2];
+    /// for (i, (n, _, _)) in nums.iter_mut().rev().enumerate() {
+    ///     *n = i;
+    /// }
+    /// assert_eq!(nums, [
+    ///     [5, 4, 3],
+    ///     [2, 1, 0],
+    /// ]);
+    /// ```
     #[inline]
     fn iter_mut(&mut self) -> GridIter<&mut Self>
     where
         Self: Sized,
     {
-        GridIter::new(self)
+        let len = self.width() * self.height();
+        GridIter::new(self, len)
+    }
+
+    /// Mutably iterate over the values of this grid, paired with the values of another grid
+    /// of the same size, with their positions. Panics if the grids are not the same size.
+    ///
+    /// ```
+    /// # use grid::{Grid, GridMut, VecGrid};
+    /// let mut a = VecGrid::with_store(2, 2, vec![1, 2, 3, 4]);
+    /// let b = VecGrid::with_store(2, 2, vec![10, 20, 30, 40]);
+    /// for (a, b, _, _) in a.zip_mut(&b) {
+    ///     *a += *b;
+    /// }
+    /// assert_eq!(a.as_slice(), &[11, 22, 33, 44]);
+    /// ```
+    #[inline]
+    fn zip_mut<'a, G2: Grid>(&'a mut self, other: &'a G2) -> ZipIter<&'a mut Self, &'a G2>
+    where
+        Self: Sized,
+    {
+        assert!(self.same_size(other), "grids are not the same size");
+        ZipIter::new(self, other)
     }
 
     /// Mutably iterate over all columns in the grid.
@@ -169,6 +897,10 @@ pub trait GridMut: Grid {
     where
         Self: Sized,
     {
+        if let Some(slice) = self.contiguous_slice_mut() {
+            slice.fill_with(f);
+            return;
+        }
         for mut row in self.rows_mut() {
             row.fill_with(&mut f);
         }
@@ -181,6 +913,10 @@ pub trait GridMut: Grid {
         Self: Sized,
         Self::Item: Clone,
     {
+        if let Some(slice) = self.contiguous_slice_mut() {
+            slice.fill(value);
+            return;
+        }
         let mut rows = self.rows_mut();
         if let Some(mut row) = rows.next() {
             for mut row in rows {
@@ -190,6 +926,452 @@ pub trait GridMut: Grid {
         }
     }
 
+    /// Modify every value in the grid in place by calling `f` on it.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut pixels = [
+    ///     [10, 20],
+    ///     [30, 40],
+    /// ];
+    ///
+    /// pixels.apply(|p| *p *= 2);
+    /// assert_eq!(pixels, [
+    ///     [20, 40],
+    ///     [60, 80],
+    /// ]);
+    /// ```
+    #[inline]
+    fn apply<F: FnMut(&mut Self::Item)>(&mut self, mut f: F)
+    where
+        Self: Sized,
+    {
+        if let Some(slice) = self.contiguous_slice_mut() {
+            slice.iter_mut().for_each(f);
+            return;
+        }
+        for mut row in self.rows_mut() {
+            row.iter_mut().for_each(&mut f);
+        }
+    }
+
+    /// Like [`apply`](Self::apply), but `f` is also given the `(x, y)` position of each
+    /// value.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[1; 3]; 2];
+    /// nums.apply_indexed(|x, y, n| *n += x + y * 10);
+    /// assert_eq!(nums, [
+    ///     [1, 2, 3],
+    ///     [11, 12, 13],
+    /// ]);
+    /// ```
+    #[inline]
+    fn apply_indexed<F: FnMut(usize, usize, &mut Self::Item)>(&mut self, mut f: F)
+    where
+        Self: Sized,
+    {
+        for mut row in self.rows_mut() {
+            let y = row.index();
+            for (x, val) in row.iter_mut().enumerate() {
+                f(x, y, val);
+            }
+        }
+    }
+
+    /// Fill the `w × h` rectangle at `(x, y)` with the provided value. The rectangle is
+    /// clipped to the grid's bounds, so only the overlapping region is filled, and
+    /// nothing happens if they do not overlap.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 0, 0],
+    /// ];
+    ///
+    /// nums.fill_rect(2, 1, 5, 5, 1);
+    /// assert_eq!(nums, [
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 1, 1],
+    ///     [0, 0, 1, 1],
+    /// ]);
+    ///
+    /// nums.fill_rect(4, 0, 2, 2, 2);
+    /// assert_eq!(nums, [
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 1, 1],
+    ///     [0, 0, 1, 1],
+    /// ]);
+    /// ```
+    #[inline]
+    fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, value: Self::Item)
+    where
+        Self::Item: Clone,
+    {
+        let w = w.min(self.width().saturating_sub(x));
+        let h = h.min(self.height().saturating_sub(y));
+        if w > 0 && h > 0 {
+            self.view_mut(x, y, w, h).fill(value);
+        }
+    }
+
+    /// Draw the outline of the `w × h` rectangle at `(x, y)` with the provided value,
+    /// leaving its interior untouched. Like [`fill_rect`](Self::fill_rect), the rectangle
+    /// is clipped to the grid's bounds. Use [`draw_rect_filled`](Self::draw_rect_filled)
+    /// to fill the interior as well.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 5]; 4];
+    ///
+    /// nums.draw_rect_outline(1, 0, 4, 3, 1);
+    /// nums.draw_rect_outline(0, 3, 9, 1, 2);
+    /// nums.draw_rect_outline(0, usize::MAX, 3, 3, 3);
+    /// assert_eq!(nums, [
+    ///     [0, 1, 1, 1, 1],
+    ///     [0, 1, 0, 0, 1],
+    ///     [0, 1, 1, 1, 1],
+    ///     [2, 2, 2, 2, 2],
+    /// ]);
+    /// ```
+    fn draw_rect_outline(&mut self, x: usize, y: usize, w: usize, h: usize, value: Self::Item)
+    where
+        Self::Item: Clone,
+    {
+        if w == 0 || h == 0 || x >= self.width() || y >= self.height() {
+            return;
+        }
+        self.fill_rect(x, y, w, 1, value.clone());
+        if let Some(bottom) = y.checked_add(h - 1).filter(|_| h > 1) {
+            self.fill_rect(x, bottom, w, 1, value.clone());
+        }
+        if h > 2 {
+            self.fill_rect(x, y + 1, 1, h - 2, value.clone());
+            if let Some(right) = x.checked_add(w - 1).filter(|_| w > 1) {
+                self.fill_rect(right, y + 1, 1, h - 2, value);
+            }
+        }
+    }
+
+    /// Draw the `w × h` rectangle at `(x, y)` filled with the provided value, clipped to the
+    /// grid's bounds. This is the same as [`fill_rect`](Self::fill_rect), and pairs with
+    /// [`draw_rect_outline`](Self::draw_rect_outline).
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 4]; 3];
+    ///
+    /// nums.draw_rect_filled(0, 0, 4, 3, 1);
+    /// nums.draw_rect_outline(0, 0, 4, 3, 2);
+    /// assert_eq!(nums, [
+    ///     [2, 2, 2, 2],
+    ///     [2, 1, 1, 2],
+    ///     [2, 2, 2, 2],
+    /// ]);
+    /// ```
+    #[inline]
+    fn draw_rect_filled(&mut self, x: usize, y: usize, w: usize, h: usize, value: Self::Item)
+    where
+        Self::Item: Clone,
+    {
+        self.fill_rect(x, y, w, h, value);
+    }
+
+    /// Like [`fill_rect`](Self::fill_rect), but each value is provided by calling `f` with
+    /// its `(x, y)` position in the grid.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 0, 0],
+    /// ];
+    ///
+    /// nums.fill_rect_with(1, 1, 5, 5, |x, y| x + y * 10);
+    /// assert_eq!(nums, [
+    ///     [0, 0, 0, 0],
+    ///     [0, 11, 12, 13],
+    ///     [0, 21, 22, 23],
+    /// ]);
+    /// ```
+    #[inline]
+    fn fill_rect_with<F>(&mut self, x: usize, y: usize, w: usize, h: usize, mut f: F)
+    where
+        F: FnMut(usize, usize) -> Self::Item,
+    {
+        let w = w.min(self.width().saturating_sub(x));
+        let h = h.min(self.height().saturating_sub(y));
+        if w > 0 && h > 0 {
+            self.view_mut(x, y, w, h)
+                .apply_indexed(|vx, vy, val| *val = f(x + vx, y + vy));
+        }
+    }
+
+    /// Draw a line from `a` to `b` (inclusive) using Bresenham's algorithm. Any points of
+    /// the line that are outside of the grid are skipped.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 5]; 4];
+    ///
+    /// nums.draw_line((-1, -1), (2, 2), 1);
+    /// nums.draw_line((4, 0), (3, 3), 2);
+    /// assert_eq!(nums, [
+    ///     [1, 0, 0, 0, 2],
+    ///     [0, 1, 0, 0, 2],
+    ///     [0, 0, 1, 2, 0],
+    ///     [0, 0, 0, 2, 0],
+    /// ]);
+    /// ```
+    #[inline]
+    fn draw_line(&mut self, a: (i32, i32), b: (i32, i32), value: Self::Item)
+    where
+        Self::Item: Clone,
+    {
+        self.draw_line_with(a, b, |_, _| value.clone());
+    }
+
+    /// Draw the outline of a circle centered at `center` using the midpoint circle
+    /// algorithm. Any points of the circle that are outside of the grid are skipped, so
+    /// circles centered off the grid still draw their visible arc. A `radius` of zero
+    /// draws only the center, and nothing is drawn if `radius` is negative.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 7]; 7];
+    ///
+    /// nums.draw_circle((3, 3), 3, 1);
+    /// nums.draw_circle((3, 3), 0, 2);
+    /// assert_eq!(nums, [
+    ///     [0, 0, 1, 1, 1, 0, 0],
+    ///     [0, 1, 0, 0, 0, 1, 0],
+    ///     [1, 0, 0, 0, 0, 0, 1],
+    ///     [1, 0, 0, 2, 0, 0, 1],
+    ///     [1, 0, 0, 0, 0, 0, 1],
+    ///     [0, 1, 0, 0, 0, 1, 0],
+    ///     [0, 0, 1, 1, 1, 0, 0],
+    /// ]);
+    ///
+    /// let before = nums;
+    /// nums.draw_circle((3, 3), 100, 3);
+    /// assert_eq!(nums, before);
+    ///
+    /// let mut arc = [[0; 4]; 3];
+    /// arc.draw_circle((-1, 1), 2, 1);
+    /// assert_eq!(arc, [
+    ///     [0, 1, 0, 0],
+    ///     [0, 1, 0, 0],
+    ///     [0, 1, 0, 0],
+    /// ]);
+    /// ```
+    fn draw_circle(&mut self, center: (i32, i32), radius: i32, value: Self::Item)
+    where
+        Self::Item: Clone,
+    {
+        let (cx, cy) = (center.0 as i64, center.1 as i64);
+        for_each_octant(radius, |x, y| {
+            for (px, py) in [
+                (cx + x, cy + y),
+                (cx + y, cy + x),
+                (cx - y, cy + x),
+                (cx - x, cy + y),
+                (cx - x, cy - y),
+                (cx - y, cy - x),
+                (cx + y, cy - x),
+                (cx + x, cy - y),
+            ] {
+                if let Some(val) = self.get_mut_at((px, py)) {
+                    *val = value.clone();
+                }
+            }
+        });
+    }
+
+    /// Fill a circle centered at `center`, covering the same area as the outline drawn by
+    /// [`draw_circle`](Self::draw_circle). The circle is clipped to the grid's bounds, and
+    /// nothing is drawn if `radius` is negative.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 6]; 5];
+    ///
+    /// nums.fill_circle((2, 2), 2, 1);
+    /// nums.fill_circle((6, 0), 1, 2);
+    /// assert_eq!(nums, [
+    ///     [0, 1, 1, 1, 0, 2],
+    ///     [1, 1, 1, 1, 1, 0],
+    ///     [1, 1, 1, 1, 1, 0],
+    ///     [1, 1, 1, 1, 1, 0],
+    ///     [0, 1, 1, 1, 0, 0],
+    /// ]);
+    /// ```
+    fn fill_circle(&mut self, center: (i32, i32), radius: i32, value: Self::Item)
+    where
+        Self::Item: Clone,
+    {
+        let (cx, cy) = (center.0 as i64, center.1 as i64);
+        let mut span = |x0: i64, x1: i64, y: i64| {
+            let (Ok(y), Ok(x)) = (usize::try_from(y), usize::try_from(x0.max(0))) else {
+                return;
+            };
+            if let Ok(w) = usize::try_from(x1 - x as i64 + 1) {
+                self.fill_rect(x, y, w, 1, value.clone());
+            }
+        };
+        for_each_octant(radius, |x, y| {
+            span(cx - x, cx + x, cy + y);
+            span(cx - x, cx + x, cy - y);
+            span(cx - y, cx + y, cy + x);
+            span(cx - y, cx + y, cy - x);
+        });
+    }
+
+    /// Like [`draw_line`](Self::draw_line), but each value is provided by calling `f` with
+    /// its `(x, y)` position in the grid. Points are visited in order from `a` to `b`.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [[0; 4]; 1];
+    /// let mut i = 0;
+    /// nums.draw_line_with((3, 0), (0, 0), |_, _| {
+    ///     i += 1;
+    ///     i
+    /// });
+    /// assert_eq!(nums, [[4, 3, 2, 1]]);
+    /// ```
+    fn draw_line_with<F>(&mut self, a: (i32, i32), b: (i32, i32), mut f: F)
+    where
+        F: FnMut(usize, usize) -> Self::Item,
+    {
+        let (mut x, mut y) = (a.0 as i64, a.1 as i64);
+        let (x1, y1) = (b.0 as i64, b.1 as i64);
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let sx = if x < x1 { 1 } else { -1 };
+        let sy = if y < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        loop {
+            if let (Ok(px), Ok(py)) = (usize::try_from(x), usize::try_from(y)) {
+                if let Some(val) = self.get_mut(px, py) {
+                    *val = f(px, py);
+                }
+            }
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = err * 2;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Replace the 4-connected region of values equal to the one at `(x, y)` with `value`.
+    /// Does nothing if `(x, y)` is out of bounds or already equal to `value`. This is the
+    /// same as calling [`flood_fill`](crate::flood_fill) on the grid.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut map = [
+    ///     ['#', '#', '#', '#', '.'],
+    ///     ['#', '.', '.', '#', '.'],
+    ///     ['#', '.', '#', '#', '.'],
+    ///     ['#', '#', '#', '.', '.'],
+    /// ];
+    ///
+    /// map.flood_fill(1, 1, 'x');
+    /// assert_eq!(map, [
+    ///     ['#', '#', '#', '#', '.'],
+    ///     ['#', 'x', 'x', '#', '.'],
+    ///     ['#', 'x', '#', '#', '.'],
+    ///     ['#', '#', '#', '.', '.'],
+    /// ]);
+    /// ```
+    #[inline]
+    fn flood_fill(&mut self, x: usize, y: usize, value: Self::Item)
+    where
+        Self::Item: Clone + PartialEq,
+        Self: Sized,
+    {
+        crate::flood_fill(self, x, y, value);
+    }
+
+    /// Copy the `(x, y, w, h)` rectangle `src` to the position `dst` within this same grid.
+    /// Both rectangles are clipped to the grid's bounds, and they are allowed to overlap,
+    /// like with [`slice::copy_within`].
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [1, 2, 3, 0],
+    ///     [4, 5, 6, 0],
+    ///     [0, 0, 0, 0],
+    /// ];
+    ///
+    /// nums.copy_within((0, 0, 3, 2), (1, 1));
+    /// assert_eq!(nums, [
+    ///     [1, 2, 3, 0],
+    ///     [4, 1, 2, 3],
+    ///     [0, 4, 5, 6],
+    /// ]);
+    ///
+    /// nums.copy_within((1, 1, 3, 1), (0, 1));
+    /// assert_eq!(nums, [
+    ///     [1, 2, 3, 0],
+    ///     [1, 2, 3, 3],
+    ///     [0, 4, 5, 6],
+    /// ]);
+    /// ```
+    fn copy_within(&mut self, src: (usize, usize, usize, usize), dst: (usize, usize))
+    where
+        Self::Item: Copy,
+    {
+        let (sx, sy, w, h) = src;
+        let (dx, dy) = dst;
+        let (gw, gh) = self.size();
+        let w = w.min(gw.saturating_sub(sx)).min(gw.saturating_sub(dx));
+        let h = h.min(gh.saturating_sub(sy)).min(gh.saturating_sub(dy));
+        if w == 0 || h == 0 {
+            return;
+        }
+        let copy_row = |grid: &mut Self, row: usize| {
+            let (sy, dy) = (sy + row, dy + row);
+            if sy == dy {
+                if let Some(slice) = grid.row_slice_mut(sy) {
+                    slice.copy_within(sx..(sx + w), dx);
+                    return;
+                }
+            }
+            let mut copy = |col: usize| {
+                let val = *grid.get(sx + col, sy).unwrap();
+                *grid.get_mut(dx + col, dy).unwrap() = val;
+            };
+            if sy == dy && dx > sx {
+                (0..w).rev().for_each(&mut copy);
+            } else {
+                (0..w).for_each(&mut copy);
+            }
+        };
+        if dy > sy {
+            for row in (0..h).rev() {
+                copy_row(self, row);
+            }
+        } else {
+            for row in 0..h {
+                copy_row(self, row);
+            }
+        }
+    }
+
     /// Clone all values from a source grid into this one. Panics if the grids
     /// are not the same size.
     #[inline]
@@ -221,6 +1403,176 @@ pub trait GridMut: Grid {
             dst.draw_copied(src);
         }
     }
+
+    /// Clone the top-left region of a source grid into this one, clipped to the size that
+    /// both grids share. Returns `true` if the entire source grid fit.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut dst = [[0; 3]; 2];
+    ///
+    /// assert!(!dst.try_draw_cloned(&[[1, 2], [3, 4], [5, 6]]));
+    /// assert_eq!(dst, [
+    ///     [1, 2, 0],
+    ///     [3, 4, 0],
+    /// ]);
+    ///
+    /// assert!(dst.try_draw_cloned(&[[7]]));
+    /// assert_eq!(dst[0][0], 7);
+    /// ```
+    #[inline]
+    fn try_draw_cloned<G2>(&mut self, src: &G2) -> bool
+    where
+        G2: Grid<Item = Self::Item>,
+        G2::Item: Clone,
+        Self: Sized,
+    {
+        let w = self.width().min(src.width());
+        let h = self.height().min(src.height());
+        if w > 0 && h > 0 {
+            self.view_mut(0, 0, w, h).draw_cloned(&src.view(0, 0, w, h));
+        }
+        w == src.width() && h == src.height()
+    }
+
+    /// Clone all values from a source grid into this one, with the source's top-left placed
+    /// at `(dx, dy)`, clipping anything that falls outside of this grid. This is the same as
+    /// [`draw_cloned_at`](Self::draw_cloned_at), but with `isize` offsets.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut dst = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    ///
+    /// dst.draw_at(&[[1, 2], [3, 4]], -1, 2);
+    /// dst.draw_at(&[[5, 6], [7, 8]], 2, -1);
+    /// assert_eq!(dst, [
+    ///     [0, 0, 7],
+    ///     [0, 0, 0],
+    ///     [2, 0, 0],
+    /// ]);
+    /// ```
+    #[inline]
+    fn draw_at<G2>(&mut self, src: &G2, dx: isize, dy: isize)
+    where
+        G2: Grid<Item = Self::Item>,
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        if let Some((dx, dy, sx, sy, w, h)) = clip_draw(self, src, dx as i64, dy as i64) {
+            self.view_mut(dx, dy, w, h)
+                .draw_cloned(&src.view(sx, sy, w, h));
+        }
+    }
+
+    /// Combine all values from a source grid into this one, by calling `f` with each
+    /// value in this grid and the corresponding value in the source. Panics if the grids
+    /// are not the same size.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut dst = [
+    ///     [1, 2],
+    ///     [3, 4],
+    /// ];
+    ///
+    /// dst.draw_with(&[[10, 20], [30, 40]], |dst, src| *dst += src);
+    /// assert_eq!(dst, [
+    ///     [11, 22],
+    ///     [33, 44],
+    /// ]);
+    /// ```
+    #[inline]
+    fn draw_with<G2, F>(&mut self, grid: &G2, mut f: F)
+    where
+        G2: Grid,
+        F: FnMut(&mut Self::Item, &G2::Item),
+        Self: Sized,
+    {
+        assert!(self.same_size(grid), "grids are not the same size");
+        for (mut dst, src) in self.rows_mut().zip(grid.rows()) {
+            if let (Some(dst), Some(src)) = (dst.as_mut_slice(), src.as_slice()) {
+                for (dst, src) in dst.iter_mut().zip(src) {
+                    f(dst, src);
+                }
+            } else {
+                for (dst, src) in dst.iter_mut().zip(src.iter()) {
+                    f(dst, src);
+                }
+            }
+        }
+    }
+
+    /// Like [`draw_with`](Self::draw_with), but with the source's top-left placed at
+    /// `(x, y)`. Any part of the source that does not overlap this grid is clipped, so the
+    /// grids can be different sizes.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut dst = [
+    ///     [1, 1, 1],
+    ///     [1, 1, 1],
+    /// ];
+    ///
+    /// dst.draw_with_at(&[[5, 6], [7, 8]], 2, -1, |dst, src| *dst *= src);
+    /// assert_eq!(dst, [
+    ///     [1, 1, 7],
+    ///     [1, 1, 1],
+    /// ]);
+    /// ```
+    #[inline]
+    fn draw_with_at<G2, F>(&mut self, src: &G2, x: i32, y: i32, f: F)
+    where
+        G2: Grid,
+        F: FnMut(&mut Self::Item, &G2::Item),
+        Self: Sized,
+    {
+        if let Some((dx, dy, sx, sy, w, h)) = clip_draw(self, src, x as i64, y as i64) {
+            self.view_mut(dx, dy, w, h)
+                .draw_with(&src.view(sx, sy, w, h), f);
+        }
+    }
+
+    /// Clone all values from a source grid into this one, with the source's top-left
+    /// placed at `(x, y)`. Any part of the source that does not overlap this grid is
+    /// clipped, so the position is allowed to be negative or extend past the edges.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut dst = [
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 0, 0],
+    /// ];
+    ///
+    /// let sprite = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    ///
+    /// dst.draw_cloned_at(&sprite, -1, -1);
+    /// dst.draw_cloned_at(&sprite, 2, 1);
+    /// assert_eq!(dst, [
+    ///     [5, 6, 0, 0],
+    ///     [0, 0, 1, 2],
+    ///     [0, 0, 4, 5],
+    /// ]);
+    /// ```
+    #[inline]
+    fn draw_cloned_at<G2>(&mut self, src: &G2, x: i32, y: i32)
+    where
+        G2: Grid<Item = Self::Item>,
+        G2::Item: Clone,
+        Self: Sized,
+    {
+        if let Some((dx, dy, sx, sy, w, h)) = clip_draw(self, src, x as i64, y as i64) {
+            self.view_mut(dx, dy, w, h)
+                .draw_cloned(&src.view(sx, sy, w, h));
+        }
+    }
 }
 
 impl<T, const W: usize, const H: usize> GridMut for [[T; W]; H] {
@@ -247,4 +1599,74 @@ impl<T, const W: usize, const H: usize> GridMut for [[T; W]; H] {
     fn row_slice_mut(&mut self, y: usize) -> Option<&mut [Self::Item]> {
         (y < H).then(|| self[y].as_mut_slice())
     }
+
+    #[inline]
+    fn contiguous_slice_mut(&mut self) -> Option<&mut [Self::Item]> {
+        Some(self.as_flattened_mut())
+    }
+}
+
+/// Reverse the cells `start..end` of row `y`.
+#[inline]
+fn reverse_cells<G: GridMut + ?Sized>(grid: &mut G, y: usize, start: usize, end: usize) {
+    let (mut a, mut b) = (start, end);
+    while a + 1 < b {
+        b -= 1;
+        grid.swap((a, y), (b, y));
+        a += 1;
+    }
+}
+
+/// Reverse the order of the rows `start..end`.
+#[inline]
+fn reverse_rows<G: GridMut + ?Sized>(grid: &mut G, start: usize, end: usize) {
+    let (mut a, mut b) = (start, end);
+    while a + 1 < b {
+        b -= 1;
+        grid.swap_rows(a, b);
+        a += 1;
+    }
+}
+
+/// Call `plot` with each point of the first octant of a circle of the provided radius,
+/// centered at the origin, using the midpoint circle algorithm.
+#[inline]
+fn for_each_octant(radius: i32, mut plot: impl FnMut(i64, i64)) {
+    let (mut x, mut y) = (radius as i64, 0);
+    let mut err = 1 - x;
+    while x >= y {
+        plot(x, y);
+        y += 1;
+        if err < 0 {
+            err += 2 * y + 1;
+        } else {
+            x -= 1;
+            err += 2 * (y - x) + 1;
+        }
+    }
+}
+
+/// Clip a `src` grid drawn at `(x, y)` against `dst`, returning the destination position,
+/// source position, and size of the overlapping region, or `None` if they do not overlap.
+#[inline]
+fn clip_draw<A: Grid, B: Grid>(
+    dst: &A,
+    src: &B,
+    x: i64,
+    y: i64,
+) -> Option<(usize, usize, usize, usize, usize, usize)> {
+    fn clip(pos: i64, src_len: usize, dst_len: usize) -> Option<(usize, usize, usize)> {
+        let start = pos.max(0);
+        let end = pos.saturating_add(src_len as i64).min(dst_len as i64);
+        (start < end).then(|| {
+            (
+                start as usize,
+                (start - pos) as usize,
+                (end - start) as usize,
+            )
+        })
+    }
+    let (dx, sx, w) = clip(x, src.width(), dst.width())?;
+    let (dy, sy, h) = clip(y, src.height(), dst.height())?;
+    Some((dx, dy, sx, sy, w, h))
 }
@@ -470,54 +470,96 @@
 //! This crate has no dependencies outside of the std, but some convenient implementations
 //! are provided behind features that you can optionally enable.
 //!
-//! | Feature    | Description                                              |
-//! | ---------- | -------------------------------------------------------- |
-//! | `serde`    | Provides [serde] implementations for [`GridBuf`].        |
-//! | `cgmath`   | Provides [`Coord`] implementations for [cgmath] vectors. |
-//! | `glam`     | Provides [`Coord`] implementations for [glam] vectors.   |
-//! | `mint`     | Provides [`Coord`] implementations for [mint] vectors.   |
-//! | `vek`      | Provides [`Coord`] implementations for [vek] vectors.    |
+//! | Feature   | Description                                                                           |
+//! | --------- | ------------------------------------------------------------------------------------- |
+//! | `serde`   | Provides [serde] implementations for [`GridBuf`], and a compact `CompactGrid` format. |
+//! | `cgmath`  | Provides [`Coord`] implementations for [cgmath] vectors.                              |
+//! | `glam`    | Provides [`Coord`] implementations for [glam] vectors.                                |
+//! | `mint`    | Provides [`Coord`] implementations for [mint] vectors.                                |
+//! | `vek`     | Provides [`Coord`] implementations for [vek] vectors.                                 |
+//! | `rayon`   | Provides parallel iterators for grids and [`GridBuf`] rows using [rayon].             |
+//! | `image`   | Provides conversions between grids and [image] buffers.                               |
+//! | `ndarray` | Provides conversions between grids and [ndarray] arrays.                              |
 //!
 //! [serde]: https://crates.io/crates/serde
 //! [cgmath]: https://crates.io/crates/cgmath
 //! [glam]: https://crates.io/crates/glam
 //! [mint]: https://crates.io/crates/mint
 //! [vek]: https://crates.io/crates/vek
+//! [rayon]: https://crates.io/crates/rayon
+//! [image]: https://crates.io/crates/image
+//! [ndarray]: https://crates.io/crates/ndarray
 
 mod col;
 mod col_iter;
 mod cols_iter;
+mod components;
+mod convolve;
 mod coord;
+mod diagonal_iter;
 #[cfg(feature = "cgmath")]
 mod feature_cgmath;
 #[cfg(feature = "glam")]
 mod feature_glam;
+#[cfg(feature = "image")]
+mod feature_image;
 #[cfg(feature = "mint")]
 mod feature_mint;
+#[cfg(feature = "ndarray")]
+mod feature_ndarray;
+#[cfg(feature = "rayon")]
+mod feature_rayon;
 #[cfg(feature = "serde")]
 mod feature_serde;
 #[cfg(feature = "vek")]
 mod feature_vek;
+mod flip;
+mod flood_fill;
 mod grid;
 mod grid_buf;
 mod grid_iter;
 mod grid_mut;
+mod neighbors_iter;
+mod rotate;
 mod row;
 mod row_iter;
 mod rows_iter;
+mod tiles_iter;
+mod torus;
+mod transpose;
 mod view;
+mod views_iter;
+mod windows_iter;
+mod zip_iter;
 
 pub use col::*;
 pub use col_iter::*;
+pub use components::*;
+pub use convolve::*;
 pub use coord::*;
+pub use diagonal_iter::*;
+#[cfg(feature = "rayon")]
+pub use feature_rayon::*;
+#[cfg(feature = "serde")]
+pub use feature_serde::*;
+pub use flip::*;
+pub use flood_fill::*;
 pub use grid::*;
 pub use grid_buf::*;
 pub use grid_iter::*;
 pub use grid_mut::*;
+pub use neighbors_iter::*;
+pub use rotate::*;
 pub use row::*;
 pub use row_iter::*;
 pub use rows_iter::*;
+pub use tiles_iter::*;
+pub use torus::*;
+pub use transpose::*;
 pub use view::*;
+pub use views_iter::*;
+pub use windows_iter::*;
+pub use zip_iter::*;
 
 #[test]
 fn test() {
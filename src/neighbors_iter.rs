@@ -0,0 +1,100 @@
+use crate::Grid;
+use std::iter::FusedIterator;
+
+/// Offsets of the 4 orthogonal neighbors of a cell, clockwise starting from north.
+pub(crate) const NEIGHBORS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 neighbors of a cell, clockwise starting from north.
+pub(crate) const NEIGHBORS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Iterator over the in-bounds neighbors of a cell, and their positions.
+#[derive(Clone)]
+pub struct NeighborsIter<GridRef> {
+    grid: GridRef,
+    x: usize,
+    y: usize,
+    offsets: &'static [(isize, isize)],
+    wrap: bool,
+}
+
+impl<GridRef> NeighborsIter<GridRef> {
+    #[inline]
+    pub(crate) fn new(
+        grid: GridRef,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> Self {
+        Self {
+            grid,
+            x,
+            y,
+            offsets,
+            wrap: false,
+        }
+    }
+
+    /// Like [`new`](Self::new), but neighbors past the grid's edges wrap around to the
+    /// opposite side, and are yielded with their wrapped positions.
+    #[inline]
+    pub(crate) fn new_wrapping(
+        grid: GridRef,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> Self {
+        Self {
+            wrap: true,
+            ..Self::new(grid, x, y, offsets)
+        }
+    }
+}
+
+/// Offset `pos` by `delta` within `0..len`, wrapping around the edges if `wrap` is set, or
+/// returning `None` if the result is out of bounds.
+#[inline]
+fn offset(pos: usize, delta: isize, len: usize, wrap: bool) -> Option<usize> {
+    if wrap {
+        (len > 0).then(|| (pos as i128 + delta as i128).rem_euclid(len as i128) as usize)
+    } else {
+        pos.checked_add_signed(delta).filter(|&pos| pos < len)
+    }
+}
+
+impl<'a, G: Grid> Iterator for NeighborsIter<&'a G> {
+    type Item = (&'a G::Item, usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((&(dx, dy), rest)) = self.offsets.split_first() {
+            self.offsets = rest;
+            let (w, h) = (self.grid.width(), self.grid.height());
+            let (Some(x), Some(y)) = (
+                offset(self.x, dx, w, self.wrap),
+                offset(self.y, dy, h, self.wrap),
+            ) else {
+                continue;
+            };
+            if let Some(val) = self.grid.get(x, y) {
+                return Some((val, x, y));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
+impl<G: Grid> FusedIterator for NeighborsIter<&G> {}
@@ -0,0 +1,210 @@
+use crate::{Grid, GridMut};
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+
+/// A grid rotated clockwise by a number of quarter turns.
+#[repr(C)]
+#[derive(Clone)]
+pub struct Rotate90<GridRef> {
+    grid: GridRef,
+    turns: u8,
+}
+
+impl<GridRef> Rotate90<GridRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef, turns: u8) -> Self {
+        Self { grid, turns }
+    }
+}
+
+/// Map a coordinate in the rotated grid to a coordinate in the inner `w × h` grid.
+/// The coordinate must already be known to be in bounds of the rotated grid.
+#[inline]
+fn to_inner(turns: u8, w: usize, h: usize, x: usize, y: usize) -> (usize, usize) {
+    match turns {
+        1 => (y, h - 1 - x),
+        2 => (w - 1 - x, h - 1 - y),
+        3 => (w - 1 - y, x),
+        _ => (x, y),
+    }
+}
+
+impl<'a, G> Deref for Rotate90<&'a mut G> {
+    type Target = Rotate90<&'a G>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<'a, G> From<Rotate90<&'a mut G>> for Rotate90<&'a G> {
+    #[inline]
+    fn from(Rotate90 { grid, turns }: Rotate90<&'a mut G>) -> Self {
+        Self { grid, turns }
+    }
+}
+
+impl<G: Grid> Grid for Rotate90<&G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        if self.turns == 2 {
+            self.grid.width()
+        } else {
+            self.grid.height()
+        }
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        if self.turns == 2 {
+            self.grid.height()
+        } else {
+            self.grid.width()
+        }
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        if x < self.width() && y < self.height() {
+            let (w, h) = self.grid.size();
+            let (x, y) = to_inner(self.turns, w, h, x, y);
+            self.grid.get(x, y)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        let (w, h) = self.grid.size();
+        let (x, y) = to_inner(self.turns, w, h, x, y);
+        self.grid.get_unchecked(x, y)
+    }
+
+    #[inline]
+    fn row_slice(&self, _y: usize) -> Option<&[Self::Item]> {
+        None
+    }
+}
+
+impl<G: Grid> Grid for Rotate90<&mut G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        if self.turns == 2 {
+            self.grid.width()
+        } else {
+            self.grid.height()
+        }
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        if self.turns == 2 {
+            self.grid.height()
+        } else {
+            self.grid.width()
+        }
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        if x < self.width() && y < self.height() {
+            let (w, h) = self.grid.size();
+            let (x, y) = to_inner(self.turns, w, h, x, y);
+            self.grid.get(x, y)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        let (w, h) = self.grid.size();
+        let (x, y) = to_inner(self.turns, w, h, x, y);
+        self.grid.get_unchecked(x, y)
+    }
+
+    #[inline]
+    fn row_slice(&self, _y: usize) -> Option<&[Self::Item]> {
+        None
+    }
+}
+
+impl<G: GridMut> GridMut for Rotate90<&mut G> {
+    type RootMut = Self;
+
+    #[inline]
+    fn root_mut(&mut self) -> &mut Self::RootMut {
+        self
+    }
+
+    #[inline]
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Item> {
+        if x < self.width() && y < self.height() {
+            let (w, h) = self.grid.size();
+            let (x, y) = to_inner(self.turns, w, h, x, y);
+            self.grid.get_mut(x, y)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut Self::Item {
+        let (w, h) = self.grid.size();
+        let (x, y) = to_inner(self.turns, w, h, x, y);
+        self.grid.get_unchecked_mut(x, y)
+    }
+
+    #[inline]
+    fn row_slice_mut(&mut self, _y: usize) -> Option<&mut [Self::Item]> {
+        None
+    }
+}
+
+impl<G: Grid> Debug for Rotate90<&G>
+where
+    G::Item: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.debug_fmt(f)
+    }
+}
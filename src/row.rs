@@ -169,6 +169,27 @@ impl<'a, G: GridMut> Row<&'a mut G> {
         }
     }
 
+    /// Reverse the order of the values in the row.
+    ///
+    /// ```
+    /// # use grid::{GridMut, VecGrid};
+    /// let mut grid = VecGrid::with_store(4, 2, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    /// grid.row_mut(0).reverse();
+    /// grid.view_mut(1, 1, 3, 1).row_mut(0).reverse();
+    /// assert_eq!(grid.as_slice(), &[4, 3, 2, 1, 5, 8, 7, 6]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        if let Some(slice) = self.as_mut_slice() {
+            slice.reverse();
+        } else {
+            let len = self.len();
+            for x in 0..(len / 2) {
+                self.grid.swap((x, self.y), (len - 1 - x, self.y));
+            }
+        }
+    }
+
     /// Clone all values from the provided row to this one.
     #[inline]
     pub fn draw_cloned<G2>(&mut self, row: impl Into<Row<&'a G2>>)
@@ -0,0 +1,104 @@
+use crate::{Grid, GridMut, View};
+use std::iter::FusedIterator;
+
+/// Iterator over non-overlapping tiles of a grid, in row-major order.
+#[derive(Clone)]
+pub struct TilesIter<GridRef> {
+    grid: GridRef,
+    x: usize,
+    y: usize,
+    tw: usize,
+    th: usize,
+}
+
+impl<GridRef> TilesIter<GridRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef, tw: usize, th: usize) -> Self {
+        assert!(tw > 0 && th > 0, "tile size must be non-zero");
+        Self {
+            grid,
+            x: 0,
+            y: 0,
+            tw,
+            th,
+        }
+    }
+
+    /// Returns the region of the next tile, or `None` if iteration is done, and advances.
+    #[inline]
+    fn advance(&mut self, w: usize, h: usize) -> Option<(usize, usize, usize, usize)> {
+        if w == 0 || self.y >= h {
+            return None;
+        }
+        let (x, y) = (self.x, self.y);
+        self.x += self.tw;
+        if self.x >= w {
+            self.x = 0;
+            self.y += self.th;
+        }
+        Some((x, y, self.tw.min(w - x), self.th.min(h - y)))
+    }
+
+    #[inline]
+    fn remaining(&self, w: usize, h: usize) -> usize {
+        if w == 0 || self.y >= h {
+            return 0;
+        }
+        let cols = w.div_ceil(self.tw);
+        let rows = h.div_ceil(self.th);
+        rows * cols - (self.y / self.th) * cols - self.x / self.tw
+    }
+}
+
+impl<'a, G: Grid> Iterator for TilesIter<&'a G> {
+    type Item = View<&'a G::Root>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, w, h) = self.advance(self.grid.width(), self.grid.height())?;
+        Some(self.grid.view(x, y, w, h))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<G: Grid> ExactSizeIterator for TilesIter<&G> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining(self.grid.width(), self.grid.height())
+    }
+}
+
+impl<G: Grid> FusedIterator for TilesIter<&G> {}
+
+impl<'a, G: GridMut> Iterator for TilesIter<&'a mut G> {
+    type Item = View<&'a mut G::RootMut>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, w, h) = self.advance(self.grid.width(), self.grid.height())?;
+        // SAFETY: tiles never overlap, so each view yielded will only ever access its own
+        // cells, which no other view from this iterator can access.
+        let grid: *mut G = self.grid;
+        Some(unsafe { &mut *grid }.view_mut(x, y, w, h))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<G: GridMut> ExactSizeIterator for TilesIter<&mut G> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining(self.grid.width(), self.grid.height())
+    }
+}
+
+impl<G: GridMut> FusedIterator for TilesIter<&mut G> {}
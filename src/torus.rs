@@ -0,0 +1,276 @@
+use crate::neighbors_iter::{NEIGHBORS4, NEIGHBORS8};
+use crate::{Grid, GridMut, NeighborsIter};
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+
+/// A grid whose edges wrap around toroidally, so that coordinates past the right or bottom
+/// edge continue from the left or top edge.
+///
+/// The width and height are unchanged from the wrapped grid, so iterating over the torus
+/// visits each cell once, but [`get`](Grid::get) accepts any `(x, y)` by taking it modulo
+/// the size, and [`neighbors`](Grid::neighbors) (and the methods built on it, like
+/// [`count_neighbors`](Grid::count_neighbors) and [`step_into`](Grid::step_into)), as well
+/// as [`neighbors4`](Grid::neighbors4) and [`neighbors8`](Grid::neighbors8), wrap around the
+/// edges and yield wrapped positions.
+///
+/// ```
+/// # use grid::{Grid, VecGrid};
+/// let grid = VecGrid::from_fn(4, 3, |x, y| (x, y));
+/// let torus = grid.torus_view();
+///
+/// let corner: Vec<_> = torus.neighbors4(0, 0).map(|(_, x, y)| (x, y)).collect();
+/// assert_eq!(corner, vec![(0, 2), (1, 0), (0, 1), (3, 0)]);
+///
+/// let corner: Vec<_> = torus.neighbors4(3, 2).map(|(&val, x, y)| (val, x, y)).collect();
+/// assert_eq!(corner, vec![((3, 1), 3, 1), ((0, 2), 0, 2), ((3, 0), 3, 0), ((2, 2), 2, 2)]);
+///
+/// assert_eq!(torus.neighbors8(0, 0).count(), 8);
+/// assert_eq!(torus.count_neighbors(0, 0, |&(x, _)| x == 3), 3);
+/// assert_eq!(torus.count_neighbors4(0, 0, |&(_, y)| y == 2), 1);
+/// ```
+#[repr(C)]
+#[derive(Clone)]
+pub struct Torus<GridRef> {
+    grid: GridRef,
+}
+
+impl<GridRef> Torus<GridRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef) -> Self {
+        Self { grid }
+    }
+}
+
+impl<'a, G> Deref for Torus<&'a mut G> {
+    type Target = Torus<&'a G>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<'a, G> From<Torus<&'a mut G>> for Torus<&'a G> {
+    #[inline]
+    fn from(Torus { grid }: Torus<&'a mut G>) -> Self {
+        Self { grid }
+    }
+}
+
+/// Wrap `(x, y)` into the bounds of a `w × h` grid, or `None` if the grid is empty.
+#[inline]
+fn wrap(x: usize, y: usize, w: usize, h: usize) -> Option<(usize, usize)> {
+    (w > 0 && h > 0).then(|| (x % w, y % h))
+}
+
+impl<G: Grid> Grid for Torus<&G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        let (x, y) = wrap(x, y, self.grid.width(), self.grid.height())?;
+        self.grid.get(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        self.grid.get_unchecked(x, y)
+    }
+
+    #[inline]
+    fn neighbors(&self, x: usize, y: usize) -> [Option<&Self::Item>; 8] {
+        self.grid.neighbors_wrapping(x, y)
+    }
+
+    #[inline]
+    fn neighbors4(&self, x: usize, y: usize) -> NeighborsIter<&Self> {
+        NeighborsIter::new_wrapping(self, x, y, &NEIGHBORS4)
+    }
+
+    #[inline]
+    fn neighbors8(&self, x: usize, y: usize) -> NeighborsIter<&Self> {
+        NeighborsIter::new_wrapping(self, x, y, &NEIGHBORS8)
+    }
+
+    #[inline]
+    fn count_neighbors<F>(&self, x: usize, y: usize, mut pred: F) -> u8
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.neighbors8(x, y)
+            .filter(|(val, _, _)| pred(val))
+            .count() as u8
+    }
+
+    #[inline]
+    fn count_neighbors4<F>(&self, x: usize, y: usize, mut pred: F) -> u8
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.neighbors4(x, y)
+            .filter(|(val, _, _)| pred(val))
+            .count() as u8
+    }
+
+    #[inline]
+    fn row_slice(&self, y: usize) -> Option<&[Self::Item]> {
+        self.grid.row_slice(y)
+    }
+
+    #[inline]
+    fn contiguous_slice(&self) -> Option<&[Self::Item]> {
+        self.grid.contiguous_slice()
+    }
+}
+
+impl<G: Grid> Grid for Torus<&mut G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        let (x, y) = wrap(x, y, self.grid.width(), self.grid.height())?;
+        self.grid.get(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        self.grid.get_unchecked(x, y)
+    }
+
+    #[inline]
+    fn neighbors(&self, x: usize, y: usize) -> [Option<&Self::Item>; 8] {
+        self.grid.neighbors_wrapping(x, y)
+    }
+
+    #[inline]
+    fn neighbors4(&self, x: usize, y: usize) -> NeighborsIter<&Self> {
+        NeighborsIter::new_wrapping(self, x, y, &NEIGHBORS4)
+    }
+
+    #[inline]
+    fn neighbors8(&self, x: usize, y: usize) -> NeighborsIter<&Self> {
+        NeighborsIter::new_wrapping(self, x, y, &NEIGHBORS8)
+    }
+
+    #[inline]
+    fn count_neighbors<F>(&self, x: usize, y: usize, mut pred: F) -> u8
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.neighbors8(x, y)
+            .filter(|(val, _, _)| pred(val))
+            .count() as u8
+    }
+
+    #[inline]
+    fn count_neighbors4<F>(&self, x: usize, y: usize, mut pred: F) -> u8
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.neighbors4(x, y)
+            .filter(|(val, _, _)| pred(val))
+            .count() as u8
+    }
+
+    #[inline]
+    fn row_slice(&self, y: usize) -> Option<&[Self::Item]> {
+        self.grid.row_slice(y)
+    }
+
+    #[inline]
+    fn contiguous_slice(&self) -> Option<&[Self::Item]> {
+        self.grid.contiguous_slice()
+    }
+}
+
+impl<G: GridMut> GridMut for Torus<&mut G> {
+    type RootMut = Self;
+
+    #[inline]
+    fn root_mut(&mut self) -> &mut Self::RootMut {
+        self
+    }
+
+    #[inline]
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Item> {
+        let (x, y) = wrap(x, y, self.grid.width(), self.grid.height())?;
+        self.grid.get_mut(x, y)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut Self::Item {
+        self.grid.get_unchecked_mut(x, y)
+    }
+
+    #[inline]
+    fn row_slice_mut(&mut self, y: usize) -> Option<&mut [Self::Item]> {
+        self.grid.row_slice_mut(y)
+    }
+
+    #[inline]
+    fn contiguous_slice_mut(&mut self) -> Option<&mut [Self::Item]> {
+        self.grid.contiguous_slice_mut()
+    }
+}
+
+impl<G: Grid> Debug for Torus<&G>
+where
+    G::Item: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.debug_fmt(f)
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{Grid, GridMut};
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+
+/// A grid with its x and y axes swapped, so that its rows are the wrapped grid's
+/// columns and vice versa.
+#[repr(C)]
+#[derive(Clone)]
+pub struct Transpose<GridRef> {
+    grid: GridRef,
+}
+
+impl<GridRef> Transpose<GridRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef) -> Self {
+        Self { grid }
+    }
+}
+
+impl<'a, G> Deref for Transpose<&'a mut G> {
+    type Target = Transpose<&'a G>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<'a, G> From<Transpose<&'a mut G>> for Transpose<&'a G> {
+    #[inline]
+    fn from(Transpose { grid }: Transpose<&'a mut G>) -> Self {
+        Self { grid }
+    }
+}
+
+impl<G: Grid> Grid for Transpose<&G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        self.grid.get(y, x)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        self.grid.get_unchecked(y, x)
+    }
+
+    #[inline]
+    fn row_slice(&self, _y: usize) -> Option<&[Self::Item]> {
+        None
+    }
+}
+
+impl<G: Grid> Grid for Transpose<&mut G> {
+    type Item = G::Item;
+    type Root = Self;
+
+    #[inline]
+    fn root(&self) -> &Self::Root {
+        self
+    }
+
+    #[inline]
+    fn root_x(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn root_y(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.grid.height()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.grid.width()
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item> {
+        self.grid.get(y, x)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Item {
+        self.grid.get_unchecked(y, x)
+    }
+
+    #[inline]
+    fn row_slice(&self, _y: usize) -> Option<&[Self::Item]> {
+        None
+    }
+}
+
+impl<G: GridMut> GridMut for Transpose<&mut G> {
+    type RootMut = Self;
+
+    #[inline]
+    fn root_mut(&mut self) -> &mut Self::RootMut {
+        self
+    }
+
+    #[inline]
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Item> {
+        self.grid.get_mut(y, x)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut Self::Item {
+        self.grid.get_unchecked_mut(y, x)
+    }
+
+    #[inline]
+    fn row_slice_mut(&mut self, _y: usize) -> Option<&mut [Self::Item]> {
+        None
+    }
+}
+
+impl<G: Grid> Debug for Transpose<&G>
+where
+    G::Item: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.debug_fmt(f)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{Grid, GridMut};
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::ops::Deref;
 
 /// Sub-section of a larger grid.
@@ -19,6 +19,70 @@ impl<GridRef> View<GridRef> {
     }
 }
 
+impl<G> View<&mut G> {
+    /// Split the view into two non-overlapping views at column `x`, with the left view
+    /// containing columns `0..x` and the right view containing the rest. Panics if `x` is
+    /// greater than the view's width.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    ///
+    /// let (mut left, mut right) = nums.view_mut(0, 0, 3, 2).split_at_col(1);
+    /// left.fill(1);
+    /// right.fill(2);
+    /// assert_eq!(nums, [
+    ///     [1, 2, 2],
+    ///     [1, 2, 2],
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn split_at_col(self, x: usize) -> (Self, Self) {
+        assert!(x <= self.w, "column index out of bounds");
+        let grid: *mut G = self.grid;
+        // SAFETY: the two views cover disjoint columns, so neither will ever access
+        // cells that the other can access.
+        let left = Self::new(unsafe { &mut *grid }, self.x, self.y, x, self.h);
+        let right = Self::new(self.grid, self.x + x, self.y, self.w - x, self.h);
+        (left, right)
+    }
+
+    /// Split the view into two non-overlapping views at row `y`, with the top view
+    /// containing rows `0..y` and the bottom view containing the rest. Panics if `y` is
+    /// greater than the view's height.
+    ///
+    /// ```
+    /// # use grid::GridMut;
+    /// let mut nums = [
+    ///     [0, 0],
+    ///     [0, 0],
+    ///     [0, 0],
+    /// ];
+    ///
+    /// let (mut top, mut bottom) = nums.view_mut(0, 0, 2, 3).split_at_row(2);
+    /// top.fill(1);
+    /// bottom.fill(2);
+    /// assert_eq!(nums, [
+    ///     [1, 1],
+    ///     [1, 1],
+    ///     [2, 2],
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn split_at_row(self, y: usize) -> (Self, Self) {
+        assert!(y <= self.h, "row index out of bounds");
+        let grid: *mut G = self.grid;
+        // SAFETY: the two views cover disjoint rows, so neither will ever access
+        // cells that the other can access.
+        let top = Self::new(unsafe { &mut *grid }, self.x, self.y, self.w, y);
+        let bottom = Self::new(self.grid, self.x, self.y + y, self.w, self.h - y);
+        (top, bottom)
+    }
+}
+
 impl<'a, G> Deref for View<&'a mut G> {
     type Target = View<&'a G>;
 
@@ -102,6 +166,19 @@ impl<G: Grid> Grid for View<&G> {
             None
         }
     }
+
+    #[inline]
+    fn contiguous_slice(&self) -> Option<&[Self::Item]> {
+        let w = self.grid.width();
+        if self.x == 0 && self.w == w {
+            let start = self.y * w;
+            self.grid
+                .contiguous_slice()
+                .and_then(|s| s.get(start..(start + self.h * w)))
+        } else {
+            None
+        }
+    }
 }
 
 impl<G: Grid> Grid for View<&mut G> {
@@ -157,6 +234,19 @@ impl<G: Grid> Grid for View<&mut G> {
             None
         }
     }
+
+    #[inline]
+    fn contiguous_slice(&self) -> Option<&[Self::Item]> {
+        let w = self.grid.width();
+        if self.x == 0 && self.w == w {
+            let start = self.y * w;
+            self.grid
+                .contiguous_slice()
+                .and_then(|s| s.get(start..(start + self.h * w)))
+        } else {
+            None
+        }
+    }
 }
 
 impl<G: GridMut> GridMut for View<&mut G> {
@@ -191,6 +281,19 @@ impl<G: GridMut> GridMut for View<&mut G> {
             None
         }
     }
+
+    #[inline]
+    fn contiguous_slice_mut(&mut self) -> Option<&mut [Self::Item]> {
+        let w = self.grid.width();
+        if self.x == 0 && self.w == w {
+            let start = self.y * w;
+            self.grid
+                .contiguous_slice_mut()
+                .and_then(|s| s.get_mut(start..(start + self.h * w)))
+        } else {
+            None
+        }
+    }
 }
 
 impl<A: Grid, B: Grid> PartialEq<View<&B>> for View<&A>
@@ -212,3 +315,13 @@ where
         self.debug_fmt(f)
     }
 }
+
+impl<G: Grid> Display for View<&G>
+where
+    G::Item: Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.display_fmt(f)
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{Grid, View};
+use std::iter::FusedIterator;
+
+/// Iterator over a grid divided into a fixed number of roughly-equal views, in row-major
+/// order.
+#[derive(Clone)]
+pub struct ViewsIter<GridRef> {
+    grid: GridRef,
+    cols: usize,
+    rows: usize,
+    i: usize,
+}
+
+impl<GridRef> ViewsIter<GridRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef, cols: usize, rows: usize) -> Self {
+        Self {
+            grid,
+            cols,
+            rows,
+            i: 0,
+        }
+    }
+}
+
+/// Returns the start and length of part `i` when splitting `len` into `count` parts,
+/// where the earlier parts get the remainder.
+#[inline]
+fn split(len: usize, count: usize, i: usize) -> (usize, usize) {
+    let (size, rem) = (len / count, len % count);
+    (i * size + i.min(rem), size + usize::from(i < rem))
+}
+
+impl<'a, G: Grid> Iterator for ViewsIter<&'a G> {
+    type Item = View<&'a G::Root>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.cols * self.rows {
+            return None;
+        }
+        let (x, w) = split(self.grid.width(), self.cols, self.i % self.cols);
+        let (y, h) = split(self.grid.height(), self.rows, self.i / self.cols);
+        self.i += 1;
+        Some(self.grid.view(x, y, w, h))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<G: Grid> ExactSizeIterator for ViewsIter<&G> {
+    #[inline]
+    fn len(&self) -> usize {
+        (self.cols * self.rows).saturating_sub(self.i)
+    }
+}
+
+impl<G: Grid> FusedIterator for ViewsIter<&G> {}
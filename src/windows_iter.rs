@@ -0,0 +1,77 @@
+use crate::{Grid, View};
+use std::iter::FusedIterator;
+
+/// Iterator over all overlapping windows of a grid, in row-major order.
+#[derive(Clone)]
+pub struct WindowsIter<GridRef> {
+    grid: GridRef,
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+}
+
+impl<GridRef> WindowsIter<GridRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef, w: usize, h: usize) -> Self {
+        Self {
+            grid,
+            x: 0,
+            y: 0,
+            w,
+            h,
+        }
+    }
+}
+
+impl<G: Grid> WindowsIter<&G> {
+    /// How many windows fit along each axis of the grid.
+    #[inline]
+    fn counts(&self) -> (usize, usize) {
+        if self.w == 0 || self.h == 0 {
+            return (0, 0);
+        }
+        let cols = (self.grid.width() + 1).saturating_sub(self.w);
+        let rows = (self.grid.height() + 1).saturating_sub(self.h);
+        (cols, rows)
+    }
+}
+
+impl<'a, G: Grid> Iterator for WindowsIter<&'a G> {
+    type Item = View<&'a G::Root>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (cols, rows) = self.counts();
+        if self.x >= cols || self.y >= rows {
+            return None;
+        }
+        let view = self.grid.view(self.x, self.y, self.w, self.h);
+        self.x += 1;
+        if self.x == cols {
+            self.x = 0;
+            self.y += 1;
+        }
+        Some(view)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<G: Grid> ExactSizeIterator for WindowsIter<&G> {
+    #[inline]
+    fn len(&self) -> usize {
+        let (cols, rows) = self.counts();
+        if self.x >= cols || self.y >= rows {
+            0
+        } else {
+            (rows - self.y) * cols - self.x
+        }
+    }
+}
+
+impl<G: Grid> FusedIterator for WindowsIter<&G> {}
@@ -0,0 +1,101 @@
+use crate::{Grid, GridMut};
+use std::iter::FusedIterator;
+
+/// Iterator over the values of two same-sized grids in pairs, and their positions.
+#[derive(Copy, Clone)]
+pub struct ZipIter<GridRef, OtherRef> {
+    grid: GridRef,
+    other: OtherRef,
+    x: usize,
+    y: usize,
+}
+
+impl<GridRef, OtherRef> ZipIter<GridRef, OtherRef> {
+    #[inline]
+    pub(crate) fn new(grid: GridRef, other: OtherRef) -> Self {
+        Self {
+            grid,
+            other,
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
+impl<'a, 'b, G: Grid, G2: Grid> Iterator for ZipIter<&'a G, &'b G2> {
+    type Item = (&'a G::Item, &'b G2::Item, usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.grid.get(self.x, self.y)?;
+        let b = self.other.get(self.x, self.y)?;
+        let x = self.x;
+        let y = self.y;
+        self.x += 1;
+        if self.x == self.grid.width() {
+            self.x = 0;
+            self.y += 1;
+        }
+        Some((a, b, x, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<G: Grid, G2: Grid> ExactSizeIterator for ZipIter<&G, &G2> {
+    #[inline]
+    fn len(&self) -> usize {
+        let w = self.grid.width();
+        let h = self.grid.height();
+        if self.y >= h {
+            return 0;
+        }
+        (h.saturating_sub(self.y + 1)) * w + (w - self.x)
+    }
+}
+
+impl<G: Grid, G2: Grid> FusedIterator for ZipIter<&G, &G2> {}
+
+impl<'a, 'b, G: GridMut, G2: Grid> Iterator for ZipIter<&'a mut G, &'b G2> {
+    type Item = (&'a mut G::Item, &'b G2::Item, usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let b = self.other.get(self.x, self.y)?;
+        let a: *mut G::Item = self.grid.get_mut(self.x, self.y)?;
+        let x = self.x;
+        let y = self.y;
+        self.x += 1;
+        if self.x == self.grid.width() {
+            self.x = 0;
+            self.y += 1;
+        }
+        // SAFETY: this iterator has a mutable reference to the grid, so as long as it exists, the
+        // mutable reference to the value fetched from inside the grid will also be valid.
+        Some((unsafe { &mut *a }, b, x, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<G: GridMut, G2: Grid> ExactSizeIterator for ZipIter<&mut G, &G2> {
+    #[inline]
+    fn len(&self) -> usize {
+        let w = self.grid.width();
+        let h = self.grid.height();
+        if self.y >= h {
+            return 0;
+        }
+        (h.saturating_sub(self.y + 1)) * w + (w - self.x)
+    }
+}
+
+impl<G: GridMut, G2: Grid> FusedIterator for ZipIter<&mut G, &G2> {}